@@ -19,6 +19,17 @@ pub trait EventsModule {
         #[indexed] group_fee_wallet: ManagedAddress,
         #[indexed] timestamp: u64,
         #[indexed] payment_network_id: ManagedBuffer,
-        #[indexed] payment_currencies: MultiValueEncoded<MultiValue2<TokenIdentifier, u32>>,
+        #[indexed] payment_currencies: MultiValueEncoded<
+            MultiValue2<EgldOrEsdtTokenIdentifier, u32>,
+        >,
     );
+
+    #[event("signerUpdatedEvent")]
+    fn signer_updated_event(self, #[indexed] new_signer: ManagedAddress);
+
+    #[event("walletDatabaseUpdatedEvent")]
+    fn wallet_database_updated_event(self, #[indexed] wallet_database_address: ManagedAddress);
+
+    #[event("defaultPlatformFeeWalletUpdatedEvent")]
+    fn default_platform_fee_wallet_updated_event(self, #[indexed] wallet: ManagedAddress);
 }
@@ -23,7 +23,7 @@ pub trait Factory:
         source_raise_contract: ManagedAddress,
         wallet_database_address: ManagedAddress,
         signer: ManagedAddress,
-        payment_currencies: MultiValueEncoded<MultiValue2<TokenIdentifier, u32>>,
+        payment_currencies: MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, u32>>,
     ) {
         let all_permissions = Permissions::OWNER | Permissions::ADMIN | Permissions::PAUSE;
         self.set_permissions(self.blockchain().get_caller(), all_permissions);
@@ -63,10 +63,26 @@ pub trait Factory:
         signature: ManagedBuffer,
         timestamp: u64,
         payment_network_id: ManagedBuffer,
-        currencies: MultiValueEncoded<TokenIdentifier>,
+        currencies: MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
     ) {
         let caller = self.blockchain().get_caller();
-        self.validate_signature(timestamp, &pool_id, &caller, signature);
+        let config_hash = self.pool_config_hash(
+            &pool_id,
+            &soft_cap,
+            &hard_cap,
+            &min_deposit,
+            &max_deposit,
+            &deposit_increments,
+            start_date,
+            end_date,
+            refund_enabled,
+            refund_deadline,
+            &platform_fee_wallet,
+            &group_fee_wallet,
+            &payment_network_id,
+            &currencies,
+        );
+        self.validate_signature(timestamp, &pool_id, &caller, &config_hash, signature);
         require!(
             !self.pool_ids().contains(&pool_id),
             "Pool ID already exists"
@@ -111,6 +127,7 @@ pub trait Factory:
                 &group_fee_wallet,
                 signer,
                 wallet_database_address,
+                &config_hash,
                 &raise_pool_currencies,
             )
             .deploy_from_source(
@@ -141,11 +158,43 @@ pub trait Factory:
         );
     }
 
+    #[endpoint(updateSigner)]
+    fn update_signer(&self, new_signer: ManagedAddress) {
+        self.require_caller_has_owner_or_admin_permissions();
+        self.signer().set(&new_signer);
+        self.signer_updated_event(new_signer);
+    }
+
+    #[endpoint(updateWalletDatabase)]
+    fn update_wallet_database(&self, wallet_database_address: ManagedAddress) {
+        self.require_caller_has_owner_or_admin_permissions();
+        self.wallet_database_address().set(&wallet_database_address);
+        self.wallet_database_updated_event(wallet_database_address);
+    }
+
+    #[endpoint(setDefaultPlatformFeeWallet)]
+    fn set_default_platform_fee_wallet(&self, wallet: ManagedAddress) {
+        self.require_caller_has_owner_or_admin_permissions();
+        self.default_platform_fee_wallet().set(&wallet);
+        self.default_platform_fee_wallet_updated_event(wallet);
+    }
+
+    // Returns the zero address while no default wallet is set, so pools resolving their
+    // configuration through the factory can detect it instead of failing to decode.
+    #[view(getDefaultPlatformFeeWallet)]
+    fn get_default_platform_fee_wallet(&self) -> ManagedAddress {
+        if self.default_platform_fee_wallet().is_empty() {
+            return ManagedAddress::zero();
+        }
+        self.default_platform_fee_wallet().get()
+    }
+
     fn validate_signature(
         &self,
         timestamp: u64,
         pool_id: &ManagedBuffer,
         caller: &ManagedAddress,
+        config_hash: &ManagedByteArray<Self::Api, 32>,
         signature: ManagedBuffer,
     ) {
         let mut buffer = ManagedBuffer::new();
@@ -155,10 +204,218 @@ pub trait Factory:
         let result = pool_id.dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
         buffer.append(caller.as_managed_buffer());
+        buffer.append(config_hash.as_managed_buffer());
         self.crypto()
             .verify_ed25519(signer.as_managed_buffer(), &buffer, &signature);
     }
 
+    fn pool_config_hash(
+        &self,
+        pool_id: &ManagedBuffer,
+        soft_cap: &BigUint,
+        hard_cap: &BigUint,
+        min_deposit: &BigUint,
+        max_deposit: &BigUint,
+        deposit_increments: &BigUint,
+        start_date: u64,
+        end_date: u64,
+        refund_enabled: bool,
+        refund_deadline: u64,
+        platform_fee_wallet: &ManagedAddress,
+        group_fee_wallet: &ManagedAddress,
+        payment_network_id: &ManagedBuffer,
+        currencies: &MultiValueEncoded<EgldOrEsdtTokenIdentifier>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let mut buffer = ManagedBuffer::new();
+        self.encode_argument(&mut buffer, pool_id);
+        self.encode_argument(&mut buffer, soft_cap);
+        self.encode_argument(&mut buffer, hard_cap);
+        self.encode_argument(&mut buffer, min_deposit);
+        self.encode_argument(&mut buffer, max_deposit);
+        self.encode_argument(&mut buffer, deposit_increments);
+        self.encode_argument(&mut buffer, &start_date);
+        self.encode_argument(&mut buffer, &end_date);
+        self.encode_argument(&mut buffer, &refund_enabled);
+        self.encode_argument(&mut buffer, &refund_deadline);
+        self.encode_argument(&mut buffer, platform_fee_wallet);
+        self.encode_argument(&mut buffer, group_fee_wallet);
+        self.encode_argument(&mut buffer, payment_network_id);
+        self.encode_argument(&mut buffer, &(currencies.len() as u32));
+        for currency in currencies.clone() {
+            self.encode_argument(&mut buffer, &currency);
+        }
+        self.crypto().keccak256(&buffer)
+    }
+
+    fn encode_argument<T: NestedEncode>(&self, buffer: &mut ManagedBuffer, argument: &T) {
+        let result = argument.dep_encode(buffer);
+        require!(result.is_ok(), "Could not encode");
+    }
+
     #[proxy]
     fn raise_pool_proxy(&self) -> raise_pool::Proxy<Self::Api>;
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer, Verifier};
+    use multiversx_sc_scenario::DebugApi;
+    use std::{vec, vec::Vec};
+
+    const TIMESTAMP: u64 = 100;
+    const CALLER: [u8; 32] = [3; 32];
+
+    #[derive(Clone)]
+    struct PoolConfig {
+        pool_id: &'static [u8],
+        soft_cap: u64,
+        hard_cap: u64,
+        min_deposit: u64,
+        max_deposit: u64,
+        deposit_increments: u64,
+        start_date: u64,
+        end_date: u64,
+        refund_enabled: bool,
+        refund_deadline: u64,
+        platform_fee_wallet: [u8; 32],
+        group_fee_wallet: [u8; 32],
+        payment_network_id: &'static [u8],
+        currencies: Vec<&'static [u8]>,
+    }
+
+    fn base_config() -> PoolConfig {
+        PoolConfig {
+            pool_id: b"pool-1",
+            soft_cap: 50,
+            hard_cap: 100,
+            min_deposit: 1,
+            max_deposit: 500,
+            deposit_increments: 1,
+            start_date: 1_000,
+            end_date: 2_000,
+            refund_enabled: true,
+            refund_deadline: 1_500,
+            platform_fee_wallet: [1; 32],
+            group_fee_wallet: [2; 32],
+            payment_network_id: b"multiversx",
+            currencies: vec![b"USDC-123456"],
+        }
+    }
+
+    // timestamp + pool_id + caller + config_hash, as checked by validate_signature.
+    fn deploy_payload(config: &PoolConfig) -> Vec<u8> {
+        let factory = crate::contract_obj::<DebugApi>();
+        let mut currencies = MultiValueEncoded::new();
+        for currency in &config.currencies {
+            currencies.push(EgldOrEsdtTokenIdentifier::esdt(*currency));
+        }
+        let config_hash = factory.pool_config_hash(
+            &ManagedBuffer::new_from_bytes(config.pool_id),
+            &BigUint::from(config.soft_cap),
+            &BigUint::from(config.hard_cap),
+            &BigUint::from(config.min_deposit),
+            &BigUint::from(config.max_deposit),
+            &BigUint::from(config.deposit_increments),
+            config.start_date,
+            config.end_date,
+            config.refund_enabled,
+            config.refund_deadline,
+            &ManagedAddress::from(&config.platform_fee_wallet),
+            &ManagedAddress::from(&config.group_fee_wallet),
+            &ManagedBuffer::new_from_bytes(config.payment_network_id),
+            &currencies,
+        );
+
+        let mut payload = TIMESTAMP.to_be_bytes().to_vec();
+        payload.extend_from_slice(&(config.pool_id.len() as u32).to_be_bytes());
+        payload.extend_from_slice(config.pool_id);
+        payload.extend_from_slice(&CALLER);
+        payload.extend_from_slice(&config_hash.to_byte_array());
+        payload
+    }
+
+    #[test]
+    fn deploy_signature_covers_every_config_field() {
+        DebugApi::dummy();
+        let secret = SecretKey::from_bytes(&[7; 32]).unwrap();
+        let public = PublicKey::from(&secret);
+        let signer = Keypair { secret, public };
+
+        let base = base_config();
+        let signature = signer.sign(&deploy_payload(&base));
+        assert!(public.verify(&deploy_payload(&base), &signature).is_ok());
+
+        let variants = [
+            PoolConfig {
+                pool_id: b"pool-2",
+                ..base.clone()
+            },
+            PoolConfig {
+                soft_cap: 51,
+                ..base.clone()
+            },
+            PoolConfig {
+                hard_cap: 101,
+                ..base.clone()
+            },
+            PoolConfig {
+                min_deposit: 2,
+                ..base.clone()
+            },
+            PoolConfig {
+                max_deposit: 501,
+                ..base.clone()
+            },
+            PoolConfig {
+                deposit_increments: 2,
+                ..base.clone()
+            },
+            PoolConfig {
+                start_date: 1_001,
+                ..base.clone()
+            },
+            PoolConfig {
+                end_date: 2_001,
+                ..base.clone()
+            },
+            PoolConfig {
+                refund_enabled: false,
+                ..base.clone()
+            },
+            PoolConfig {
+                refund_deadline: 1_501,
+                ..base.clone()
+            },
+            PoolConfig {
+                platform_fee_wallet: [4; 32],
+                ..base.clone()
+            },
+            PoolConfig {
+                group_fee_wallet: [5; 32],
+                ..base.clone()
+            },
+            PoolConfig {
+                payment_network_id: b"ethereum",
+                ..base.clone()
+            },
+            PoolConfig {
+                currencies: vec![b"USDT-123456"],
+                ..base.clone()
+            },
+            PoolConfig {
+                currencies: vec![b"USDC-123456", b"USDT-123456"],
+                ..base.clone()
+            },
+            PoolConfig {
+                currencies: vec![],
+                ..base.clone()
+            },
+        ];
+        for variant in &variants {
+            assert!(public.verify(&deploy_payload(variant), &signature).is_err());
+        }
+    }
+}
@@ -13,11 +13,11 @@ pub trait StorageModule {
 
     #[view(getPaymentCurrencies)]
     #[storage_mapper("payment_currencies")]
-    fn payment_currencies(&self) -> UnorderedSetMapper<TokenIdentifier>;
+    fn payment_currencies(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
 
     #[view(getCurrencyDecimals)]
     #[storage_mapper("currency_decimals")]
-    fn currency_decimals(&self, currency: &TokenIdentifier) -> SingleValueMapper<u32>;
+    fn currency_decimals(&self, currency: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u32>;
 
     #[view(getContractCreationEnabled)]
     #[storage_mapper("raise_pool_enabled")]
@@ -38,6 +38,9 @@ pub trait StorageModule {
     #[storage_mapper("signer")]
     fn signer(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[storage_mapper("default_platform_fee_wallet")]
+    fn default_platform_fee_wallet(&self) -> SingleValueMapper<ManagedAddress>;
+
     #[view(getPoolIds)]
     #[storage_mapper("pool_ids")]
     fn pool_ids(&self) -> UnorderedSetMapper<ManagedBuffer>;
@@ -0,0 +1,247 @@
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use factory::Factory;
+use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id_wrapped,
+    multiversx_chain_vm::tx_mock::TxResult, scenario_model::*, DebugApi, ScenarioWorld,
+    WhiteboxContract,
+};
+use raise_pool::{config::ConfigModule, storage::StorageModule, RaisePool};
+
+const FACTORY_PATH_EXPR: &str = "mxsc:output/factory.mxsc.json";
+const RAISE_POOL_PATH_EXPR: &str = "mxsc:../raise-pool/output/raise-pool.mxsc.json";
+
+const OWNER: &str = "address:owner";
+const PLATFORM_FEE_WALLET: &str = "address:platform-fee-wallet";
+const DEFAULT_PLATFORM_FEE_WALLET: &str = "address:default-platform-fee-wallet";
+const GROUP_FEE_WALLET: &str = "address:group-fee-wallet";
+const FACTORY: &str = "sc:factory";
+const SOURCE_RAISE_POOL: &str = "sc:source-raise-pool";
+const RAISE_POOL: &str = "sc:raise-pool";
+const WALLET_DATABASE: &str = "sc:wallet-database";
+
+const POOL_ID: &[u8] = b"pool-1";
+const PAYMENT_NETWORK_ID: &[u8] = b"multiversx";
+const TOKEN: &str = "USDC-123456";
+const TOKEN_DECIMALS: u32 = 6;
+
+const DEPLOY_TIMESTAMP: u64 = 100;
+const START_DATE: u64 = 1_000;
+const END_DATE: u64 = 2_000;
+const REFUND_DEADLINE: u64 = 1_500;
+
+type FactoryContract = WhiteboxContract<factory::ContractObj<DebugApi>>;
+type RaisePoolContract = WhiteboxContract<raise_pool::ContractObj<DebugApi>>;
+
+struct FactorySetup {
+    world: ScenarioWorld,
+    factory: FactoryContract,
+    raise_pool: RaisePoolContract,
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(FACTORY_PATH_EXPR, factory::ContractBuilder);
+    blockchain.register_contract(RAISE_POOL_PATH_EXPR, raise_pool::ContractBuilder);
+    blockchain
+}
+
+fn keypair(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+fn address(expr: &str) -> Address {
+    AddressValue::from(expr).to_address()
+}
+
+fn nested(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = (bytes.len() as u32).to_be_bytes().to_vec();
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+// timestamp + pool_id + caller + endpoint + arguments, as built by owner_call_payload.
+fn owner_call_payload(timestamp: u64, caller: &str, endpoint: &str, arguments: &[u8]) -> Vec<u8> {
+    let mut payload = timestamp.to_be_bytes().to_vec();
+    payload.extend(nested(POOL_ID));
+    payload.extend_from_slice(address(caller).as_bytes());
+    payload.extend(nested(endpoint.as_bytes()));
+    payload.extend_from_slice(arguments);
+    payload
+}
+
+impl FactorySetup {
+    fn new(signer: &Keypair) -> Self {
+        let mut world = world();
+        let raise_pool_code = world.code_expression(RAISE_POOL_PATH_EXPR);
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(OWNER, Account::new().nonce(1))
+                .put_account(
+                    SOURCE_RAISE_POOL,
+                    Account::new().code(raise_pool_code).owner(OWNER),
+                )
+                .new_address(OWNER, 1, FACTORY)
+                .new_address(FACTORY, 0, RAISE_POOL)
+                .block_timestamp(DEPLOY_TIMESTAMP),
+        );
+
+        let factory = WhiteboxContract::new(FACTORY, factory::contract_obj);
+        let factory_code = world.code_expression(FACTORY_PATH_EXPR);
+        let signer_address = Address::from(signer.public.to_bytes());
+        world.whitebox_deploy(
+            &factory,
+            ScDeployStep::new().from(OWNER).code(factory_code),
+            |sc| {
+                let mut payment_currencies = MultiValueEncoded::new();
+                payment_currencies.push((managed_token_id_wrapped!(TOKEN), TOKEN_DECIMALS).into());
+                sc.init(
+                    managed_address!(&address(SOURCE_RAISE_POOL)),
+                    managed_address!(&address(WALLET_DATABASE)),
+                    managed_address!(&signer_address),
+                    payment_currencies,
+                );
+            },
+        );
+
+        let mut config_hash = [0u8; 32];
+        world.whitebox_query(&factory, |sc| {
+            let mut currencies = MultiValueEncoded::new();
+            currencies.push(managed_token_id_wrapped!(TOKEN));
+            config_hash = sc
+                .pool_config_hash(
+                    &managed_buffer!(POOL_ID),
+                    &managed_biguint!(50),
+                    &managed_biguint!(100),
+                    &managed_biguint!(1),
+                    &managed_biguint!(500),
+                    &managed_biguint!(1),
+                    START_DATE,
+                    END_DATE,
+                    true,
+                    REFUND_DEADLINE,
+                    &managed_address!(&address(PLATFORM_FEE_WALLET)),
+                    &managed_address!(&address(GROUP_FEE_WALLET)),
+                    &managed_buffer!(PAYMENT_NETWORK_ID),
+                    &currencies,
+                )
+                .to_byte_array();
+        });
+        let mut payload = DEPLOY_TIMESTAMP.to_be_bytes().to_vec();
+        payload.extend(nested(POOL_ID));
+        payload.extend_from_slice(address(OWNER).as_bytes());
+        payload.extend_from_slice(&config_hash);
+        let signature = signer.sign(&payload).to_bytes().to_vec();
+
+        world.whitebox_call(&factory, ScCallStep::new().from(OWNER), |sc| {
+            let mut currencies = MultiValueEncoded::new();
+            currencies.push(managed_token_id_wrapped!(TOKEN));
+            sc.deploy_raise_pool(
+                managed_buffer!(POOL_ID),
+                managed_biguint!(50),
+                managed_biguint!(100),
+                managed_biguint!(1),
+                managed_biguint!(500),
+                managed_biguint!(1),
+                START_DATE,
+                END_DATE,
+                true,
+                REFUND_DEADLINE,
+                managed_address!(&address(PLATFORM_FEE_WALLET)),
+                managed_address!(&address(GROUP_FEE_WALLET)),
+                managed_buffer!(&signature),
+                DEPLOY_TIMESTAMP,
+                managed_buffer!(PAYMENT_NETWORK_ID),
+                currencies,
+            );
+        });
+
+        Self {
+            world,
+            factory,
+            raise_pool: WhiteboxContract::new(RAISE_POOL, raise_pool::contract_obj),
+        }
+    }
+
+    fn set_use_factory_config(&mut self, signer: &Keypair, value: bool) -> TxResult {
+        let payload = owner_call_payload(
+            DEPLOY_TIMESTAMP,
+            OWNER,
+            "setUseFactoryConfig",
+            &[u8::from(value)],
+        );
+        let signature = signer.sign(&payload).to_bytes().to_vec();
+        let mut result = TxResult::empty();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new().from(OWNER).no_expect(),
+            |sc| sc.set_use_factory_config(DEPLOY_TIMESTAMP, managed_buffer!(&signature), value),
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    fn call_factory(&mut self, f: impl FnOnce(factory::ContractObj<DebugApi>)) {
+        self.world
+            .whitebox_call(&self.factory, ScCallStep::new().from(OWNER), f);
+    }
+}
+
+#[test]
+fn use_factory_config_resolves_factory_values() {
+    let signer = keypair(7);
+    let new_signer = keypair(8);
+    let mut setup = FactorySetup::new(&signer);
+
+    // The factory has no default platform fee wallet yet, so the pool keeps its own values.
+    let result = setup.set_use_factory_config(&signer, true);
+    assert_eq!(result.result_message, "Factory configuration incomplete");
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert!(!sc.use_factory_config().get());
+        assert_eq!(
+            sc.platform_fee_wallet().get(),
+            managed_address!(&address(PLATFORM_FEE_WALLET))
+        );
+    });
+
+    setup.call_factory(|sc| {
+        sc.set_default_platform_fee_wallet(managed_address!(&address(DEFAULT_PLATFORM_FEE_WALLET)))
+    });
+    let result = setup.set_use_factory_config(&signer, true);
+    assert_eq!(result.result_status, 0, "{}", result.result_message);
+
+    // Factory updates are picked up by the pool.
+    let new_signer_address = Address::from(new_signer.public.to_bytes());
+    setup.call_factory(|sc| sc.update_signer(managed_address!(&new_signer_address)));
+    setup
+        .world
+        .whitebox_call(&setup.raise_pool, ScCallStep::new().from(OWNER), |sc| {
+            assert!(sc.signer().is_empty());
+            assert_eq!(sc.current_signer(), managed_address!(&new_signer_address));
+            assert_eq!(
+                sc.current_wallet_database_address(),
+                managed_address!(&address(WALLET_DATABASE))
+            );
+            assert_eq!(
+                sc.current_platform_fee_wallet(),
+                managed_address!(&address(DEFAULT_PLATFORM_FEE_WALLET))
+            );
+        });
+
+    // The previous factory signer is no longer accepted; disabling copies the values back.
+    let result = setup.set_use_factory_config(&signer, false);
+    assert_ne!(result.result_status, 0);
+    let result = setup.set_use_factory_config(&new_signer, false);
+    assert_eq!(result.result_status, 0, "{}", result.result_message);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert!(!sc.use_factory_config().get());
+        assert_eq!(sc.signer().get(), managed_address!(&new_signer_address));
+        assert_eq!(
+            sc.platform_fee_wallet().get(),
+            managed_address!(&address(DEFAULT_PLATFORM_FEE_WALLET))
+        );
+    });
+}
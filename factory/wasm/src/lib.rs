@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           19
+// Endpoints:                           23
 // Async Callback (empty):               1
-// Total number of exported functions:  22
+// Total number of exported functions:  26
 
 #![no_std]
 
@@ -21,6 +21,10 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deployRaisePool => deploy_raise_pool
+        updateSigner => update_signer
+        updateWalletDatabase => update_wallet_database
+        setDefaultPlatformFeeWallet => set_default_platform_fee_wallet
+        getDefaultPlatformFeeWallet => get_default_platform_fee_wallet
         addAdmin => add_admin_endpoint
         removeAdmin => remove_admin_endpoint
         updateOwnerOrAdmin => update_owner_or_admin_endpoint
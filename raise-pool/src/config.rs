@@ -0,0 +1,58 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::factory_proxy::ProxyTrait as _;
+
+#[multiversx_sc::module]
+pub trait ConfigModule: crate::storage::StorageModule {
+    #[view(getCurrentSigner)]
+    fn current_signer(&self) -> ManagedAddress {
+        if self.use_local_config(self.signer().is_empty()) {
+            return self.signer().get();
+        }
+        self.factory_proxy(self.factory_address().get())
+            .signer()
+            .execute_on_dest_context::<ManagedAddress>()
+    }
+
+    #[view(getCurrentWalletDatabaseAddress)]
+    fn current_wallet_database_address(&self) -> ManagedAddress {
+        if self.use_local_config(self.wallet_database_address().is_empty()) {
+            return self.wallet_database_address().get();
+        }
+        self.factory_proxy(self.factory_address().get())
+            .wallet_database_address()
+            .execute_on_dest_context::<ManagedAddress>()
+    }
+
+    #[view(getCurrentPlatformFeeWallet)]
+    fn current_platform_fee_wallet(&self) -> ManagedAddress {
+        let wallet = self.configured_platform_fee_wallet();
+        require!(
+            self.use_local_config(self.platform_fee_wallet().is_empty()) || !wallet.is_zero(),
+            "Platform fee wallet not set"
+        );
+        wallet
+    }
+
+    // Same resolution as current_platform_fee_wallet, without failing when the factory has no
+    // default wallet yet: the zero address is returned instead.
+    fn configured_platform_fee_wallet(&self) -> ManagedAddress {
+        if self.use_local_config(self.platform_fee_wallet().is_empty()) {
+            return self.platform_fee_wallet().get();
+        }
+        self.factory_proxy(self.factory_address().get())
+            .default_platform_fee_wallet()
+            .execute_on_dest_context::<ManagedAddress>()
+    }
+
+    fn use_local_config(&self, local_value_empty: bool) -> bool {
+        !self.use_factory_config().get() || !local_value_empty
+    }
+
+    #[proxy]
+    fn factory_proxy(
+        &self,
+        callee_sc_address: ManagedAddress,
+    ) -> crate::factory_proxy::Proxy<Self::Api>;
+}
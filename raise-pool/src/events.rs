@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::storage::{DepositPolicy, PenaltyTier, PoolStatus, ReleaseState, TokenPayment};
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     #[event("depositEvent")]
@@ -9,7 +11,7 @@ pub trait EventsModule {
         #[indexed] pool_id: ManagedBuffer,
         #[indexed] deposit_id: ManagedBuffer,
         #[indexed] caller: ManagedAddress,
-        #[indexed] token: TokenIdentifier,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
         #[indexed] amount: BigUint,
         #[indexed] platform_fee: BigUint,
         #[indexed] group_fee: BigUint,
@@ -19,6 +21,52 @@ pub trait EventsModule {
         #[indexed] group_ambassador: Option<ManagedAddress>,
     );
 
+    #[event("depositMultiEvent")]
+    fn deposited_multi_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] deposit_id: ManagedBuffer,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+        #[indexed] platform_fees: ManagedVec<BigUint>,
+        #[indexed] group_fees: ManagedVec<BigUint>,
+        #[indexed] user_ambassador_fees: Option<ManagedVec<BigUint>>,
+        #[indexed] user_ambassador: Option<ManagedAddress>,
+        #[indexed] group_ambassador_fees: Option<ManagedVec<BigUint>>,
+        #[indexed] group_ambassador: Option<ManagedAddress>,
+    );
+
+    #[event("depositForEvent")]
+    fn deposited_for_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] deposit_id: ManagedBuffer,
+        #[indexed] payer: ManagedAddress,
+        #[indexed] beneficiary: ManagedAddress,
+    );
+
+    #[event("withdrawEvent")]
+    fn withdraw_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+        #[indexed] penalty: BigUint,
+        #[indexed] refunded: BigUint,
+    );
+
+    #[event("poolStatusChanged")]
+    fn pool_status_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] old_status: PoolStatus,
+        #[indexed] new_status: PoolStatus,
+    );
+
+    #[event("poolCancelledEvent")]
+    fn pool_cancelled_event(self, #[indexed] pool_id: ManagedBuffer, #[indexed] timestamp: u64);
+
     #[event("changeTimestampEvent")]
     fn changed_timestamp_event(
         self,
@@ -27,4 +75,223 @@ pub trait EventsModule {
         #[indexed] new_end_date: u64,
         #[indexed] new_refund_deadline: u64,
     );
+
+    #[event("signerRotatedEvent")]
+    fn signer_rotated_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] old_signer: ManagedAddress,
+        #[indexed] new_signer: ManagedAddress,
+        #[indexed] grace_period_end: u64,
+    );
+
+    #[event("signerAddedEvent")]
+    fn signer_added_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] signer: ManagedAddress,
+    );
+
+    #[event("signerRemovedEvent")]
+    fn signer_removed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] signer: ManagedAddress,
+    );
+
+    #[event("signerThresholdChangedEvent")]
+    fn signer_threshold_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] threshold: usize,
+    );
+
+    #[event("feesClaimedEvent")]
+    fn fees_claimed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] claimant: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("roundConfiguredEvent")]
+    fn round_configured_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] round_id: usize,
+        #[indexed] name: ManagedBuffer,
+        #[indexed] start_date: u64,
+        #[indexed] end_date: u64,
+        #[indexed] cap: BigUint,
+    );
+
+    #[event("roundDepositEvent")]
+    fn round_deposit_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] deposit_id: ManagedBuffer,
+        #[indexed] round_id: usize,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] amount: BigUint,
+        #[indexed] round_total: BigUint,
+    );
+
+    #[event("refundEvent")]
+    fn refund_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] address: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("adminRefundEvent")]
+    fn admin_refund_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] address: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("userRefundEvent")]
+    fn user_refund_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] address: ManagedAddress,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+    );
+
+    #[event("overcommitRefundEvent")]
+    fn overcommit_refund_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] address: ManagedAddress,
+        #[indexed] token: EgldOrEsdtTokenIdentifier,
+        #[indexed] amount: BigUint,
+    );
+
+    #[event("allocationRefundEvent")]
+    fn allocation_refund_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] address: ManagedAddress,
+        #[indexed] allocation: BigUint,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("releaseStateChangedEvent")]
+    fn release_state_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] old_state: ReleaseState,
+        #[indexed] new_state: ReleaseState,
+    );
+
+    #[event("platformFeesReleasedEvent")]
+    fn platform_fees_released_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] wallet: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("groupFeesReleasedEvent")]
+    fn group_fees_released_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] wallet: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("ambassadorFeesReleasedEvent")]
+    fn ambassador_fees_released_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] ambassador: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("retrieveEvent")]
+    fn retrieve_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] caller: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("feesSweptEvent")]
+    fn fees_swept_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] wallet: ManagedAddress,
+        #[indexed] payments: ManagedVec<TokenPayment<Self::Api>>,
+    );
+
+    #[event("platformFeeWalletChangedEvent")]
+    fn platform_fee_wallet_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] wallet: ManagedAddress,
+    );
+
+    #[event("raisePoolEnabledEvent")]
+    fn raise_pool_enabled_event(self, #[indexed] pool_id: ManagedBuffer, #[indexed] value: bool);
+
+    #[event("refundEnabledEvent")]
+    fn refund_enabled_event(self, #[indexed] pool_id: ManagedBuffer, #[indexed] value: bool);
+
+    #[event("feeClaimModeEvent")]
+    fn fee_claim_mode_event(self, #[indexed] pool_id: ManagedBuffer, #[indexed] value: bool);
+
+    #[event("oversubscriptionEnabledEvent")]
+    fn oversubscription_enabled_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] value: bool,
+    );
+
+    #[event("useFactoryConfigEvent")]
+    fn use_factory_config_event(self, #[indexed] pool_id: ManagedBuffer, #[indexed] value: bool);
+
+    #[event("depositPolicyChangedEvent")]
+    fn deposit_policy_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] policy: DepositPolicy,
+    );
+
+    #[event("withdrawalPenaltyScheduleChangedEvent")]
+    fn withdrawal_penalty_schedule_changed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] tiers: ManagedVec<PenaltyTier>,
+    );
+
+    #[event("roundWhitelistAddedEvent")]
+    fn round_whitelist_added_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] round_id: usize,
+        #[indexed] addresses: ManagedVec<ManagedAddress>,
+    );
+
+    #[event("roundWhitelistRemovedEvent")]
+    fn round_whitelist_removed_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] round_id: usize,
+        #[indexed] addresses: ManagedVec<ManagedAddress>,
+    );
+
+    #[event("releasePlanSubmittedEvent")]
+    fn release_plan_submitted_event(
+        self,
+        #[indexed] pool_id: ManagedBuffer,
+        #[indexed] entries: usize,
+        #[indexed] plan_hash: ManagedByteArray<32>,
+        #[indexed] is_final: bool,
+    );
+
+    #[event("releasePlanClearedEvent")]
+    fn release_plan_cleared_event(self, #[indexed] pool_id: ManagedBuffer);
 }
@@ -0,0 +1,13 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::proxy]
+pub trait FactoryProxy {
+    #[view(getSigner)]
+    fn signer(&self) -> ManagedAddress;
+
+    #[view(getWalletDatabaseContract)]
+    fn wallet_database_address(&self) -> ManagedAddress;
+
+    #[view(getDefaultPlatformFeeWallet)]
+    fn default_platform_fee_wallet(&self) -> ManagedAddress;
+}
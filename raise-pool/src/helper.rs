@@ -3,11 +3,16 @@ multiversx_sc::derive_imports!();
 
 use wallet_database::ProxyTrait as _;
 
+use crate::storage::{DepositPolicy, DepositTranche, Round, RoundEligibility, TokenPayment};
+use crate::PENALTY_DENOMINATOR;
+
 pub const ALLOWED_TIMESTAMP_DELAY: u64 = 90;
 pub const DEFAULT_DECIMALS: u32 = 18;
 
 #[multiversx_sc::module]
-pub trait HelperModule: crate::storage::StorageModule {
+pub trait HelperModule:
+    crate::storage::StorageModule + crate::signers::SignersModule + crate::config::ConfigModule
+{
     fn validate_init(
         &self,
         soft_cap: &BigUint,
@@ -16,12 +21,7 @@ pub trait HelperModule: crate::storage::StorageModule {
         max_deposit: &BigUint,
         start_date: u64,
         end_date: u64,
-        _deposit_increments: &BigUint,
     ) {
-        // require!(                                                                     // DISABLED
-        //     min_deposit % deposit_increments == 0 && max_deposit % deposit_increments == 0,
-        //     "Min and max deposit amounts must be a multiple of deposit increments"
-        // );
         require!(
             soft_cap <= hard_cap,
             "Soft cap needs to be lower than hard cap"
@@ -40,15 +40,34 @@ pub trait HelperModule: crate::storage::StorageModule {
         );
     }
 
-    fn validate_deposit(&self, payment: &EsdtTokenPayment, backend_timestamp: &u64) {
+    fn deposit_payments(&self) -> ManagedVec<TokenPayment<Self::Api>> {
+        let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
+        match self.call_value().any_payment() {
+            EgldOrMultiEsdtPayment::Egld(amount) => {
+                payments.push(TokenPayment::new(EgldOrEsdtTokenIdentifier::egld(), amount));
+            }
+            EgldOrMultiEsdtPayment::MultiEsdt(esdt_payments) => {
+                for payment in esdt_payments.iter() {
+                    require!(payment.token_nonce == 0, "Invalid token payment");
+                    payments.push(TokenPayment::new(
+                        EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier),
+                        payment.amount,
+                    ));
+                }
+            }
+        }
+        for payment in payments.iter() {
+            require!(payment.amount > 0, "Payment amount cannot be zero");
+        }
+        payments
+    }
+
+    fn validate_deposit(&self, token: &EgldOrEsdtTokenIdentifier, backend_timestamp: &u64) {
         let timestamp = self.blockchain().get_block_timestamp();
         require!(
-            self.payment_currencies()
-                .contains(&payment.token_identifier),
+            self.payment_currencies().contains(token),
             "Invalid token payment"
         );
-        require!(timestamp > self.start_date().get(), "Deposits not open yet");
-        require!(timestamp < self.end_date().get(), "Deposits closed");
         require!(
             timestamp >= *backend_timestamp,
             "Backend timestamp higher than current timestamp"
@@ -57,43 +76,207 @@ pub trait HelperModule: crate::storage::StorageModule {
             timestamp - backend_timestamp < ALLOWED_TIMESTAMP_DELAY,
             "Deposit took too long"
         );
-        require!(self.raise_pool_enabled().get(), "Pool is not enabled");
-        // let min_deposit_denominated = self.match_denomination(self.min_deposit().get(), payment);  // DISABLED
-        // require!(
-        //     min_deposit_denominated <= payment.amount,
-        //     "Payment amount too low"
-        // );
+    }
 
-        let caller = self.blockchain().get_caller();
-        let mut total_caller_amount = BigUint::zero();
-        for currency in self.deposited_currencies(&caller).iter() {
-            let deposited_amount = self.deposited_amount(&caller, &currency).get();
-            let denominated_amount = self.denominate_payment(&currency, &deposited_amount);
-            total_caller_amount += denominated_amount;
+    // Min deposit and increments are expressed in whole units, so both sides of the
+    // comparison are brought to DEFAULT_DECIMALS regardless of the payment currency.
+    // Net amounts are expected already denominated, one per payment of the deposit.
+    fn validate_deposit_policy(&self, address: &ManagedAddress, net_amounts: &ManagedVec<BigUint>) {
+        match self.deposit_policy().get() {
+            DepositPolicy::Off => {}
+            DepositPolicy::PerDeposit => {
+                for net_amount in net_amounts.iter() {
+                    self.validate_deposit_amount(&net_amount);
+                }
+            }
+            DepositPolicy::CumulativePerWallet => {
+                self.validate_deposit_amount(&self.net_deposited_amount(address));
+            }
         }
+    }
 
-        // let increment = self.deposit_increments().get();                                             // DISABLED
-        // let increment_denominted = self.match_denomination(increment, payment);
-        // require!(
-        //     &payment.amount % &increment_denominted == 0,
-        //     "Payment amount is not a multiple of the deposit increment"
-        // );
+    fn validate_deposit_amount(&self, amount: &BigUint) {
+        let unit = BigUint::from(10_u64.pow(DEFAULT_DECIMALS));
+        require!(
+            *amount >= self.min_deposit().get() * &unit,
+            "Payment amount too low"
+        );
+        let increment = self.deposit_increments().get() * &unit;
+        require!(
+            amount % &increment == 0,
+            "Payment amount is not a multiple of the deposit increment"
+        );
     }
 
-    fn validate_owner_call_on_enabled_pool(&self, timestamp: u64, signature: ManagedBuffer) {
-        require!(self.raise_pool_enabled().get(), "Pool is not enabled");
-        self.validate_owner_call(timestamp, signature);
+    fn validate_round(&self, round: &Round<Self::Api>, round_id: usize) {
+        require!(
+            round.end_date > round.start_date,
+            "Round end date must be greater than round start date"
+        );
+        require!(
+            round.start_date >= self.start_date().get() && round.end_date <= self.end_date().get(),
+            "Round must be within the pool deposit window"
+        );
+        require!(
+            round.max_deposit > 0 && round.min_deposit <= round.max_deposit,
+            "Invalid round deposit limits"
+        );
+        require!(round.cap > 0, "Round cap cannot be zero");
+        for (index, other) in self.rounds().iter().enumerate() {
+            if index + 1 == round_id {
+                continue;
+            }
+            require!(
+                round.end_date <= other.start_date || round.start_date >= other.end_date,
+                "Rounds cannot overlap"
+            );
+        }
+    }
+
+    // Rounds added or changed once the sale is open can only start in the future, so they
+    // cannot reshape a window deposits already went through.
+    fn validate_round_change(&self, round: &Round<Self::Api>, round_id: usize) {
+        require!(
+            round.start_date > self.blockchain().get_block_timestamp(),
+            "Round start date must be in the future"
+        );
+        self.validate_round(round, round_id);
+    }
+
+    fn active_round(&self) -> usize {
+        let timestamp = self.blockchain().get_block_timestamp();
+        for (index, round) in self.rounds().iter().enumerate() {
+            if timestamp >= round.start_date && timestamp < round.end_date {
+                return index + 1;
+            }
+        }
+        0
+    }
+
+    // Attributes a deposit (its total net amount, denominated) to the round it landed in
+    // and enforces the round limits. Pools without rounds keep the single pool window and
+    // return round 0.
+    fn record_round_deposit(&self, address: &ManagedAddress, amount: &BigUint) -> usize {
+        if self.rounds().is_empty() {
+            return 0;
+        }
+        let round_id = self.active_round();
+        require!(round_id > 0, "No active round");
+        let round = self.rounds().get(round_id);
+        if round.eligibility == RoundEligibility::Whitelist {
+            require!(
+                self.round_whitelist(round_id).contains(address),
+                "Wallet not eligible for this round"
+            );
+        }
+
+        self.round_total(round_id).update(|total| *total += amount);
+        self.round_deposited_amount(round_id, address)
+            .update(|deposited| *deposited += amount);
+
+        let unit = BigUint::from(10_u64.pow(DEFAULT_DECIMALS));
+        let deposited = self.round_deposited_amount(round_id, address).get();
+        require!(
+            deposited >= round.min_deposit * &unit,
+            "Payment amount too low for this round"
+        );
+        require!(
+            deposited <= round.max_deposit * &unit,
+            "Round allocation exceeded"
+        );
+        require!(
+            self.round_total(round_id).get() <= round.cap * &unit,
+            "Round cap exceeded"
+        );
+        round_id
+    }
+
+    // Refunded and withdrawn amounts are given back to the rounds of the wallet, latest
+    // round first, so round caps and per-wallet round limits follow net contributions.
+    fn release_round_deposit(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        net_amount: &BigUint,
+    ) {
+        let mut remaining = self.denominate_payment(token, net_amount);
+        for round_id in (1..=self.rounds().len()).rev() {
+            if remaining == 0 {
+                break;
+            }
+            let deposited = self.round_deposited_amount(round_id, address).get();
+            let released = core::cmp::min(deposited, remaining.clone());
+            if released == 0 {
+                continue;
+            }
+            self.round_deposited_amount(round_id, address)
+                .update(|current| *current -= &released);
+            self.round_total(round_id)
+                .update(|current| *current -= &released);
+            remaining -= released;
+        }
     }
 
-    fn validate_owner_call(&self, timestamp: u64, signature: ManagedBuffer) {
-        self.validate_signature(
-            timestamp,
-            &self.pool_id().get(),
-            &self.blockchain().get_caller(),
-            self.signer().get(),
-            signature,
+    fn validate_deposit_policy_config(&self, policy: DepositPolicy) {
+        if policy == DepositPolicy::Off {
+            return;
+        }
+        let increment = self.deposit_increments().get();
+        require!(increment > 0, "Deposit increments cannot be zero");
+        require!(
+            self.min_deposit().get() % &increment == 0
+                && self.max_deposit().get() % &increment == 0,
+            "Min and max deposit amounts must be a multiple of deposit increments"
         );
+    }
+
+    fn validate_owner_call_on_enabled_pool(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
+    ) {
+        require!(self.raise_pool_enabled().get(), "Pool is not enabled");
+        self.validate_owner_call(timestamp, signature, endpoint, arguments);
+    }
+
+    fn validate_owner_call(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
+    ) {
+        self.validate_owner_call_with_threshold(timestamp, signature, endpoint, arguments, false);
+    }
+
+    fn validate_high_value_owner_call(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
+    ) {
+        self.validate_owner_call_with_threshold(timestamp, signature, endpoint, arguments, true);
+    }
+
+    fn validate_owner_call_with_threshold(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
+        high_value: bool,
+    ) {
         let caller = self.blockchain().get_caller();
+        let buffer = self.owner_call_payload(timestamp, &caller, endpoint, arguments);
+        let threshold = if high_value {
+            self.required_signatures()
+        } else {
+            1
+        };
+        self.verify_backend_signature(&buffer, &signature, threshold, high_value);
         require!(
             caller == self.owner().get(),
             "Only owner can call this function"
@@ -106,65 +289,162 @@ pub trait HelperModule: crate::storage::StorageModule {
             self.blockchain().get_block_timestamp() - timestamp < ALLOWED_TIMESTAMP_DELAY,
             "Function call took too long"
         );
+        self.consume_signed_payload(timestamp, &buffer);
     }
 
-    fn validate_signature(
+    // User endpoints signed with the same domain-separated payload as the owner endpoints.
+    fn validate_user_call(
         &self,
         timestamp: u64,
-        pool_id: &ManagedBuffer,
-        caller: &ManagedAddress,
-        signer: ManagedAddress,
         signature: ManagedBuffer,
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
     ) {
-        let mut buffer = ManagedBuffer::new();
-        let result = timestamp.dep_encode(&mut buffer);
-        require!(result.is_ok(), "Could not encode");
-        let result = pool_id.dep_encode(&mut buffer);
-        require!(result.is_ok(), "Could not encode");
-        buffer.append(caller.as_managed_buffer());
-        self.crypto()
-            .verify_ed25519(signer.as_managed_buffer(), &buffer, &signature);
+        let caller = self.blockchain().get_caller();
+        let buffer = self.owner_call_payload(timestamp, &caller, endpoint, arguments);
+        self.verify_backend_signature(&buffer, &signature, 1, false);
+        require!(
+            timestamp <= self.blockchain().get_block_timestamp(),
+            "Timestamp provided by backend set in the future"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() - timestamp < ALLOWED_TIMESTAMP_DELAY,
+            "Function call took too long"
+        );
+        self.consume_signed_payload(timestamp, &buffer);
     }
 
-    fn validate_deposit_signature(
+    fn withdrawal_penalty_rate(&self, elapsed: u64) -> u64 {
+        let mut penalty = 0;
+        for tier in self.withdrawal_penalty_schedule().iter() {
+            if tier.offset > elapsed {
+                break;
+            }
+            penalty = tier.penalty;
+        }
+        penalty
+    }
+
+    // Every deposit is a tranche with its own penalty clock and withdrawals consume the
+    // tranches oldest first, so a new deposit does not restart the schedule of older ones.
+    fn withdrawal_penalty(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) -> BigUint {
+        let timestamp = self.blockchain().get_block_timestamp();
+        let mut remaining = amount.clone();
+        let mut penalty = BigUint::zero();
+        for tranche in self.deposit_tranches(address, token).iter() {
+            if remaining == 0 {
+                break;
+            }
+            let part = if tranche.amount < remaining {
+                tranche.amount
+            } else {
+                remaining.clone()
+            };
+            let rate = self.withdrawal_penalty_rate(timestamp.saturating_sub(tranche.timestamp));
+            penalty += &part * rate / PENALTY_DENOMINATOR;
+            remaining -= part;
+        }
+        if remaining > 0 {
+            let rate =
+                self.withdrawal_penalty_rate(timestamp.saturating_sub(self.start_date().get()));
+            penalty += remaining * rate / PENALTY_DENOMINATOR;
+        }
+        penalty
+    }
+
+    fn consume_deposit_tranches(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let mut tranches = self.deposit_tranches(address, token);
+        let mut remaining = amount.clone();
+        while remaining > 0 {
+            let Some(mut tranche) = tranches.pop_front() else {
+                break;
+            };
+            if tranche.amount > remaining {
+                tranche.amount -= &remaining;
+                tranches.push_front(tranche);
+                break;
+            }
+            remaining -= tranche.amount;
+        }
+    }
+
+    fn owner_call_payload(
         &self,
         timestamp: u64,
-        pool_id: &ManagedBuffer,
         caller: &ManagedAddress,
-        platform_fee: &BigUint,
-        group_fee: &BigUint,
-        signer: ManagedAddress,
-        signature: ManagedBuffer,
-        ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
-    ) {
+        endpoint: &[u8],
+        arguments: &ManagedBuffer,
+    ) -> ManagedBuffer {
         let mut buffer = ManagedBuffer::new();
         let result = timestamp.dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
-        let result = pool_id.dep_encode(&mut buffer);
+        let result = self.pool_id().get().dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
         buffer.append(caller.as_managed_buffer());
-        let result = platform_fee.dep_encode(&mut buffer);
-        require!(result.is_ok(), "Could not encode");
-        let result = group_fee.dep_encode(&mut buffer);
+        let result = ManagedBuffer::from(endpoint).dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
-        for ambassador in ambassadors.into_iter() {
-            let (ambassador_percentage, ambassador_wallet) = ambassador.into_tuple();
-            let result = ambassador_percentage.dep_encode(&mut buffer);
-            require!(result.is_ok(), "Could not encode");
-            buffer.append(ambassador_wallet.as_managed_buffer());
+        buffer.append(arguments);
+        buffer
+    }
+
+    // A payload can only be used while its timestamp is within ALLOWED_TIMESTAMP_DELAY, so
+    // used payloads are kept per timestamp bucket and the expired buckets are dropped.
+    fn consume_signed_payload(&self, timestamp: u64, payload: &ManagedBuffer) {
+        let payload_hash = self.crypto().keccak256(payload);
+        let bucket = timestamp / ALLOWED_TIMESTAMP_DELAY;
+        require!(
+            !self.used_signatures(bucket).contains(&payload_hash),
+            "Signature already used"
+        );
+        self.prune_used_signatures();
+        self.used_signatures(bucket).insert(payload_hash);
+        self.used_signature_buckets().insert(bucket);
+    }
+
+    fn prune_used_signatures(&self) {
+        let current_bucket = self.blockchain().get_block_timestamp() / ALLOWED_TIMESTAMP_DELAY;
+        let expired_buckets: ManagedVec<u64> = self
+            .used_signature_buckets()
+            .iter()
+            .filter(|bucket| bucket + 1 < current_bucket)
+            .collect();
+        for bucket in expired_buckets.iter() {
+            self.used_signatures(bucket).clear();
+            self.used_signature_buckets().swap_remove(&bucket);
         }
-        self.crypto()
-            .verify_ed25519(signer.as_managed_buffer(), &buffer, &signature);
     }
 
-    fn validate_user_refund_call(
+    fn encode_argument<T: NestedEncode>(&self, buffer: &mut ManagedBuffer, argument: &T) {
+        let result = argument.dep_encode(buffer);
+        require!(result.is_ok(), "Could not encode");
+    }
+
+    // The payload is tagged with "deposit", or "depositFor" followed by the beneficiary, so
+    // it cannot be mistaken for another signed call. With several payments the per payment
+    // fields and the per payment ambassador fees are repeated in transfer order.
+    fn validate_deposit_signature(
         &self,
         timestamp: u64,
         pool_id: &ManagedBuffer,
         caller: &ManagedAddress,
-        token: &TokenIdentifier,
-        signer: ManagedAddress,
+        beneficiary: &Option<ManagedAddress>,
+        platform_fees: &ManagedVec<BigUint>,
+        group_fees: &ManagedVec<BigUint>,
+        payments: &ManagedVec<TokenPayment<Self::Api>>,
+        deposit_id: &ManagedBuffer,
+        max_allocation: &Option<BigUint>,
         signature: ManagedBuffer,
+        ambassadors: MultiValueEncoded<MultiValue2<ManagedVec<BigUint>, ManagedAddress>>,
     ) {
         let mut buffer = ManagedBuffer::new();
         let result = timestamp.dep_encode(&mut buffer);
@@ -172,14 +452,34 @@ pub trait HelperModule: crate::storage::StorageModule {
         let result = pool_id.dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
         buffer.append(caller.as_managed_buffer());
+        match beneficiary {
+            Some(beneficiary) => {
+                self.encode_argument(&mut buffer, &ManagedBuffer::from(b"depositFor"));
+                buffer.append(beneficiary.as_managed_buffer());
+            }
+            None => self.encode_argument(&mut buffer, &ManagedBuffer::from(b"deposit")),
+        }
+        for (index, payment) in payments.iter().enumerate() {
+            self.encode_argument(&mut buffer, &*platform_fees.get(index));
+            self.encode_argument(&mut buffer, &*group_fees.get(index));
+            self.encode_argument(&mut buffer, &payment.token_identifier);
+            self.encode_argument(&mut buffer, &payment.amount);
+        }
+        let result = deposit_id.dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
-        let result = token.dep_encode(&mut buffer);
+        let result = max_allocation.dep_encode(&mut buffer);
         require!(result.is_ok(), "Could not encode");
-        self.crypto()
-            .verify_ed25519(signer.as_managed_buffer(), &buffer, &signature);
+        for ambassador in ambassadors.into_iter() {
+            let (ambassador_fees, ambassador_wallet) = ambassador.into_tuple();
+            for ambassador_fee in ambassador_fees.iter() {
+                self.encode_argument(&mut buffer, &*ambassador_fee);
+            }
+            buffer.append(ambassador_wallet.as_managed_buffer());
+        }
+        self.verify_backend_signature(&buffer, &signature, 1, false);
     }
 
-    fn denominate_payment(&self, token: &TokenIdentifier, amount: &BigUint) -> BigUint {
+    fn denominate_payment(&self, token: &EgldOrEsdtTokenIdentifier, amount: &BigUint) -> BigUint {
         match self.currency_decimals(token).get() {
             decimals if decimals != DEFAULT_DECIMALS => {
                 amount * 10_u64.pow(DEFAULT_DECIMALS - decimals)
@@ -188,12 +488,32 @@ pub trait HelperModule: crate::storage::StorageModule {
         }
     }
 
-    fn match_denomination(&self, amount: BigUint, payment: &EsdtTokenPayment) -> BigUint {
-        let decimals = self.currency_decimals(&payment.token_identifier).get();
-        amount * 10_u64.pow(decimals)
+    fn max_deposit_denominated(&self, address: &ManagedAddress) -> BigUint {
+        let max_deposit = if self.wallet_max_allocation(address).is_empty() {
+            self.max_deposit().get()
+        } else {
+            self.wallet_max_allocation(address).get()
+        };
+        max_deposit * 10_u64.pow(DEFAULT_DECIMALS)
+    }
+
+    fn apply_wallet_max_allocation(
+        &self,
+        address: &ManagedAddress,
+        max_allocation: Option<BigUint>,
+    ) {
+        let Some(max_allocation) = max_allocation else {
+            return;
+        };
+        let stored = self.wallet_max_allocation(address);
+        if stored.is_empty() {
+            stored.set(max_allocation);
+        } else {
+            require!(stored.get() == max_allocation, "Allocation already set");
+        }
     }
 
-    fn increase_totals(&self, token_identifier: &TokenIdentifier, amount: &BigUint) {
+    fn increase_totals(&self, token_identifier: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
         let payment_denomination = self.denominate_payment(token_identifier, amount);
         self.total_amount()
             .update(|current| *current += payment_denomination);
@@ -201,7 +521,7 @@ pub trait HelperModule: crate::storage::StorageModule {
             .update(|current| *current += amount);
     }
 
-    fn decrease_totals(&self, token_identifier: &TokenIdentifier, amount: &BigUint) {
+    fn decrease_totals(&self, token_identifier: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
         let payment_denomination = self.denominate_payment(token_identifier, amount);
         self.total_amount()
             .update(|current| *current -= payment_denomination);
@@ -209,23 +529,29 @@ pub trait HelperModule: crate::storage::StorageModule {
             .update(|current| *current -= amount);
     }
 
-    fn increase_general(&self, address: &ManagedAddress, payment: &EsdtTokenPayment) {
+    fn increase_general(&self, address: &ManagedAddress, payment: &TokenPayment<Self::Api>) {
         self.addresses().insert(address.clone());
         self.deposited_currencies(address)
             .insert(payment.token_identifier.clone());
         self.deposited_amount(address, &payment.token_identifier)
             .update(|current| *current += &payment.amount);
+        self.deposit_tranches(address, &payment.token_identifier)
+            .push_back(DepositTranche {
+                timestamp: self.blockchain().get_block_timestamp(),
+                amount: payment.amount.clone(),
+            });
     }
 
-    fn remove_general(&self, address: &ManagedAddress, token: &TokenIdentifier) {
+    fn remove_general(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) {
         self.deposited_currencies(address).swap_remove(token);
         self.deposited_amount(address, token).clear();
+        self.deposit_tranches(address, token).clear();
     }
 
     fn increase_platform_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
         platform_fee: &BigUint,
     ) {
         let denominated_platform_fee = self.denominate_payment(token, platform_fee);
@@ -237,7 +563,7 @@ pub trait HelperModule: crate::storage::StorageModule {
             .update(|current| *current += &denominated_platform_fee);
     }
 
-    fn remove_platform_fee(&self, address: &ManagedAddress, token: &TokenIdentifier) {
+    fn remove_platform_fee(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) {
         let fee_amount = self.address_platform_fee(address, token).take();
         let fee_amount_denominated = self.denominate_payment(token, &fee_amount);
         self.platform_fee(token)
@@ -249,7 +575,7 @@ pub trait HelperModule: crate::storage::StorageModule {
     fn increase_group_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
         group_fee: &BigUint,
     ) {
         let denominated_group_fee = self.denominate_payment(token, group_fee);
@@ -261,7 +587,7 @@ pub trait HelperModule: crate::storage::StorageModule {
             .update(|current| *current += &denominated_group_fee);
     }
 
-    fn remove_group_fee(&self, address: &ManagedAddress, token: &TokenIdentifier) {
+    fn remove_group_fee(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) {
         let fee_amount = self.address_group_fee(address, token).take();
         let fee_amount_denominated = self.denominate_payment(token, &fee_amount);
         self.group_fee(token)
@@ -273,7 +599,7 @@ pub trait HelperModule: crate::storage::StorageModule {
     fn increase_ambassador_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
         ambassador_amount: &BigUint,
         ambassador_wallet: &ManagedAddress,
     ) {
@@ -291,9 +617,11 @@ pub trait HelperModule: crate::storage::StorageModule {
             .insert(token.clone());
         self.address_to_ambassadors(address)
             .insert(ambassador_wallet.clone());
+        self.address_referral_ambassador_fee(address, ambassador_wallet, token)
+            .update(|current| *current += ambassador_amount);
     }
 
-    fn decrease_ambassador_fee(&self, address: &ManagedAddress, token: &TokenIdentifier) {
+    fn decrease_ambassador_fee(&self, address: &ManagedAddress, token: &EgldOrEsdtTokenIdentifier) {
         let ambassador_amount = self.address_ambassador_fee(address, token).take();
         let ambassador_amount_denomination = self.denominate_payment(token, &ambassador_amount);
         self.total_ambassador_fee()
@@ -301,8 +629,11 @@ pub trait HelperModule: crate::storage::StorageModule {
         self.ambassador_fee(token)
             .update(|current| *current -= &ambassador_amount);
         for ambassador_wallet in self.address_to_ambassadors(address).iter() {
+            let referral_fee = self
+                .address_referral_ambassador_fee(address, &ambassador_wallet, token)
+                .take();
             self.referral_ambassador_fee(&ambassador_wallet, token)
-                .update(|current| *current -= &ambassador_amount);
+                .update(|current| *current -= &referral_fee);
             if self
                 .referral_ambassador_fee(&ambassador_wallet, token)
                 .get()
@@ -310,7 +641,6 @@ pub trait HelperModule: crate::storage::StorageModule {
             {
                 self.ambassador_currencies(&ambassador_wallet)
                     .swap_remove(token);
-                self.address_to_ambassadors(address).clear();
                 self.referral_ambassador_fee(&ambassador_wallet, token)
                     .clear();
             }
@@ -318,9 +648,104 @@ pub trait HelperModule: crate::storage::StorageModule {
                 self.ambassadors().swap_remove(&ambassador_wallet);
             }
         }
+        if self.deposited_currencies(address).is_empty() {
+            self.address_to_ambassadors(address).clear();
+        }
     }
 
-    fn release_token_user(&self, address: &ManagedAddress, token: &TokenIdentifier) -> BigUint {
+    fn reduce_deposit(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    ) -> TokenPayment<Self::Api> {
+        let deposited = self.deposited_amount(address, token).get();
+        if *amount >= deposited {
+            return self.release_token_admin(address, token);
+        }
+
+        let platform_fee_cut =
+            self.address_platform_fee(address, token).get() * amount / &deposited;
+        let denominated_platform_fee_cut = self.denominate_payment(token, &platform_fee_cut);
+        self.address_platform_fee(address, token)
+            .update(|current| *current -= &platform_fee_cut);
+        self.platform_fee(token)
+            .update(|current| *current -= &platform_fee_cut);
+        self.total_platform_fee()
+            .update(|current| *current -= denominated_platform_fee_cut);
+
+        let group_fee_cut = self.address_group_fee(address, token).get() * amount / &deposited;
+        let denominated_group_fee_cut = self.denominate_payment(token, &group_fee_cut);
+        self.address_group_fee(address, token)
+            .update(|current| *current -= &group_fee_cut);
+        self.group_fee(token)
+            .update(|current| *current -= &group_fee_cut);
+        self.total_group_fee()
+            .update(|current| *current -= denominated_group_fee_cut);
+
+        let mut ambassador_fee_cut = BigUint::zero();
+        for ambassador_wallet in self.address_to_ambassadors(address).iter() {
+            let referral_fee_cut = self
+                .address_referral_ambassador_fee(address, &ambassador_wallet, token)
+                .get()
+                * amount
+                / &deposited;
+            self.address_referral_ambassador_fee(address, &ambassador_wallet, token)
+                .update(|current| *current -= &referral_fee_cut);
+            self.referral_ambassador_fee(&ambassador_wallet, token)
+                .update(|current| *current -= &referral_fee_cut);
+            ambassador_fee_cut += referral_fee_cut;
+        }
+        let denominated_ambassador_fee_cut = self.denominate_payment(token, &ambassador_fee_cut);
+        self.address_ambassador_fee(address, token)
+            .update(|current| *current -= &ambassador_fee_cut);
+        self.ambassador_fee(token)
+            .update(|current| *current -= &ambassador_fee_cut);
+        self.total_ambassador_fee()
+            .update(|current| *current -= denominated_ambassador_fee_cut);
+
+        let net_amount = amount - &platform_fee_cut - &group_fee_cut - &ambassador_fee_cut;
+        self.release_round_deposit(address, token, &net_amount);
+        self.consume_deposit_tranches(address, token, amount);
+        self.deposited_amount(address, token)
+            .update(|current| *current -= amount);
+        self.decrease_totals(token, amount);
+        TokenPayment::new(token.clone(), amount.clone())
+    }
+
+    fn net_total_amount(&self) -> BigUint {
+        self.total_amount().get()
+            - self.total_ambassador_fee().get()
+            - self.total_group_fee().get()
+            - self.total_platform_fee().get()
+    }
+
+    fn net_token_deposit(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        self.deposited_amount(address, token).get()
+            - self.address_platform_fee(address, token).get()
+            - self.address_group_fee(address, token).get()
+            - self.address_ambassador_fee(address, token).get()
+    }
+
+    fn net_deposited_amount(&self, address: &ManagedAddress) -> BigUint {
+        let mut net_amount = BigUint::zero();
+        for token in self.deposited_currencies(address).iter() {
+            let amount = self.net_token_deposit(address, &token);
+            net_amount += self.denominate_payment(&token, &amount);
+        }
+        net_amount
+    }
+
+    fn release_token_user(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        self.release_round_deposit(address, token, &self.net_token_deposit(address, token));
         let mut amount = self.deposited_amount(address, token).get();
         let platform_fee = self.address_platform_fee(address, token).get();
         let ambassador_fee = self.address_ambassador_fee(address, token).get();
@@ -335,9 +760,10 @@ pub trait HelperModule: crate::storage::StorageModule {
     fn release_token_admin(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
-    ) -> EsdtTokenPayment {
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> TokenPayment<Self::Api> {
         let amount = self.deposited_amount(address, token).get();
+        self.release_round_deposit(address, token, &self.net_token_deposit(address, token));
         self.deposited_currencies(address).swap_remove(token);
         self.decrease_totals(token, &amount);
         self.remove_general(address, token);
@@ -346,11 +772,74 @@ pub trait HelperModule: crate::storage::StorageModule {
         if !self.address_to_ambassadors(address).is_empty() {
             self.decrease_ambassador_fee(address, token);
         }
-        EsdtTokenPayment::new(token.clone(), 0, amount)
+        TokenPayment::new(token.clone(), amount)
+    }
+
+    fn release_all_tokens_admin(
+        &self,
+        address: &ManagedAddress,
+    ) -> ManagedVec<TokenPayment<Self::Api>> {
+        let tokens: ManagedVec<EgldOrEsdtTokenIdentifier> =
+            self.deposited_currencies(address).iter().collect();
+        let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
+        for token in tokens.iter() {
+            let payment = self.release_token_admin(address, &token);
+            if payment.amount > 0 {
+                payments.push(payment);
+            }
+        }
+        payments
+    }
+
+    fn unclaimed_fees(&self, token: &EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.platform_fee(token).get()
+            + self.group_fee(token).get()
+            + self.ambassador_fee(token).get()
+    }
+
+    fn claimable_fee(
+        &self,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let mut amount = BigUint::zero();
+        if *address == self.current_platform_fee_wallet() {
+            amount += self.platform_fee(token).get();
+        }
+        if *address == self.group_fee_wallet().get() {
+            amount += self.group_fee(token).get();
+        }
+        amount += self.referral_ambassador_fee(address, token).get();
+        amount
+    }
+
+    fn take_claimable_fees(&self, address: &ManagedAddress) -> ManagedVec<TokenPayment<Self::Api>> {
+        let is_platform = *address == self.current_platform_fee_wallet();
+        let is_group = *address == self.group_fee_wallet().get();
+        let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
+        for token in self.payment_currencies().iter() {
+            let mut amount = BigUint::zero();
+            if is_platform {
+                amount += self.platform_fee(&token).take();
+            }
+            if is_group {
+                amount += self.group_fee(&token).take();
+            }
+            let ambassador_amount = self.referral_ambassador_fee(address, &token).take();
+            if ambassador_amount > 0 {
+                self.ambassador_fee(&token)
+                    .update(|current| *current -= &ambassador_amount);
+                amount += ambassador_amount;
+            }
+            if amount > 0 {
+                payments.push(TokenPayment::new(token, amount));
+            }
+        }
+        payments
     }
 
     fn is_registered(&self, address: &ManagedAddress) -> bool {
-        self.wallet_database_proxy(self.wallet_database_address().get())
+        self.wallet_database_proxy(self.current_wallet_database_address())
             .is_registered(address)
             .execute_on_dest_context::<bool>()
     }
@@ -358,10 +847,23 @@ pub trait HelperModule: crate::storage::StorageModule {
     fn send_multi_if_not_empty(
         &self,
         address: &ManagedAddress,
-        payments: &ManagedVec<EsdtTokenPayment>,
+        payments: &ManagedVec<TokenPayment<Self::Api>>,
     ) {
-        if !payments.is_empty() {
-            self.send().direct_multi(address, payments);
+        // EGLD cannot be part of a multi transfer, so it is sent separately from the ESDTs.
+        let mut esdt_payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+        for payment in payments.iter() {
+            if payment.token_identifier.is_egld() {
+                self.send().direct_egld(address, &payment.amount);
+            } else {
+                esdt_payments.push(EsdtTokenPayment::new(
+                    payment.token_identifier.unwrap_esdt(),
+                    0,
+                    payment.amount,
+                ));
+            }
+        }
+        if !esdt_payments.is_empty() {
+            self.send().direct_multi(address, &esdt_payments);
         }
     }
 
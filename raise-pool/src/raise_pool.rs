@@ -1,18 +1,37 @@
 #![no_std]
 
 use multiversx_sc::imports::*;
+pub mod config;
 pub mod events;
+pub mod factory_proxy;
 pub mod helper;
+pub mod signers;
+pub mod status;
 pub mod storage;
+pub mod views;
+use crate::factory_proxy::ProxyTrait as _;
 use crate::helper::DEFAULT_DECIMALS;
-use storage::ReleaseState;
+use storage::{
+    Allocation, Deposit, DepositPolicy, OvercommitEntry, PenaltyTier, PoolStatus, ReleaseState,
+    Round, RoundEligibility, TokenPayment,
+};
 
 pub const MIN_GAS_FOR_OPERATION: u64 = 2_000_000;
 pub const MAX_TX_PER_RELEASE: u32 = 140;
+pub const MAX_SIGNER_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60;
+pub const REFUND_CLAIM_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60;
+pub const FEE_CLAIM_PERIOD: u64 = 30 * 24 * 60 * 60;
+pub const PENALTY_DENOMINATOR: u64 = 10_000;
 
 #[multiversx_sc::contract]
 pub trait RaisePool:
-    crate::storage::StorageModule + crate::helper::HelperModule + events::EventsModule
+    crate::storage::StorageModule
+    + crate::helper::HelperModule
+    + crate::signers::SignersModule
+    + crate::config::ConfigModule
+    + events::EventsModule
+    + status::StatusModule
+    + views::ViewsModule
 {
     #[init]
     fn init(
@@ -32,7 +51,8 @@ pub trait RaisePool:
         group_fee_wallet: ManagedAddress,
         signer: ManagedAddress,
         wallet_database_address: ManagedAddress,
-        payment_currencies: MultiValueEncoded<MultiValue2<TokenIdentifier, u32>>,
+        config_hash: ManagedByteArray<Self::Api, 32>,
+        payment_currencies: MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, u32>>,
     ) {
         self.validate_init(
             &soft_cap,
@@ -41,7 +61,6 @@ pub trait RaisePool:
             &max_deposit,
             start_date,
             end_date,
-            &deposit_increments,
         );
         self.soft_cap().set(soft_cap);
         self.hard_cap().set(hard_cap);
@@ -63,8 +82,13 @@ pub trait RaisePool:
         self.wallet_database_address().set(wallet_database_address);
         self.signer().set(signer);
         self.pool_id().set(pool_id);
+        self.config_hash().set(config_hash);
         self.release_state().set(ReleaseState::None);
         self.owner().set(owner);
+        let caller = self.blockchain().get_caller();
+        if self.blockchain().is_smart_contract(&caller) {
+            self.factory_address().set(caller);
+        }
     }
 
     #[upgrade]
@@ -82,103 +106,351 @@ pub trait RaisePool:
         ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
     ) {
         let caller = self.blockchain().get_caller();
-        let signer = self.signer().get();
+        let (platform_fees, group_fees, ambassadors) =
+            self.single_payment_fees(platform_fee, group_fee, ambassadors);
+        self.process_deposit(
+            &caller,
+            None,
+            timestamp,
+            signature,
+            platform_fees,
+            group_fees,
+            deposit_id,
+            None,
+            ambassadors,
+            false,
+        );
+    }
+
+    // Same as deposit with a signed per-wallet limit; kept separate so the positional
+    // arguments of deposit stay unchanged for existing callers.
+    #[payable("*")]
+    #[endpoint(depositWithAllocation)]
+    fn deposit_with_allocation(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        platform_fee: BigUint,
+        group_fee: BigUint,
+        deposit_id: ManagedBuffer,
+        max_allocation: BigUint,
+        ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        let (platform_fees, group_fees, ambassadors) =
+            self.single_payment_fees(platform_fee, group_fee, ambassadors);
+        self.process_deposit(
+            &caller,
+            None,
+            timestamp,
+            signature,
+            platform_fees,
+            group_fees,
+            deposit_id,
+            Some(max_allocation),
+            ambassadors,
+            false,
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(depositFor)]
+    fn deposit_for(
+        &self,
+        beneficiary: ManagedAddress,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        platform_fee: BigUint,
+        group_fee: BigUint,
+        deposit_id: ManagedBuffer,
+        max_allocation: Option<BigUint>,
+        ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
+    ) {
+        let payer = self.blockchain().get_caller();
+        let (platform_fees, group_fees, ambassadors) =
+            self.single_payment_fees(platform_fee, group_fee, ambassadors);
+        self.process_deposit(
+            &payer,
+            Some(beneficiary),
+            timestamp,
+            signature,
+            platform_fees,
+            group_fees,
+            deposit_id,
+            max_allocation,
+            ambassadors,
+            false,
+        );
+    }
+
+    #[payable("*")]
+    #[endpoint(depositMulti)]
+    fn deposit_multi(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        platform_fees: ManagedVec<BigUint>,
+        group_fees: ManagedVec<BigUint>,
+        deposit_id: ManagedBuffer,
+        max_allocation: Option<BigUint>,
+        ambassadors: MultiValueEncoded<MultiValue2<ManagedVec<BigUint>, ManagedAddress>>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.process_deposit(
+            &caller,
+            None,
+            timestamp,
+            signature,
+            platform_fees,
+            group_fees,
+            deposit_id,
+            max_allocation,
+            ambassadors,
+            true,
+        );
+    }
+
+    // deposit and depositFor keep their single payment arguments; the fees are wrapped in
+    // the per payment vectors used by depositMulti.
+    fn single_payment_fees(
+        &self,
+        platform_fee: BigUint,
+        group_fee: BigUint,
+        ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
+    ) -> (
+        ManagedVec<BigUint>,
+        ManagedVec<BigUint>,
+        MultiValueEncoded<MultiValue2<ManagedVec<BigUint>, ManagedAddress>>,
+    ) {
+        require!(
+            self.call_value().all_esdt_transfers().len() <= 1,
+            "Use depositMulti for several payments"
+        );
+        let mut ambassador_fees = MultiValueEncoded::new();
+        for ambassador in ambassadors {
+            let (fee, wallet) = ambassador.into_tuple();
+            ambassador_fees.push(MultiValue2((ManagedVec::from_single_item(fee), wallet)));
+        }
+        (
+            ManagedVec::from_single_item(platform_fee),
+            ManagedVec::from_single_item(group_fee),
+            ambassador_fees,
+        )
+    }
+
+    // All the bookkeeping of a deposit is keyed to the beneficiary, the payer only funds it.
+    fn process_deposit(
+        &self,
+        payer: &ManagedAddress,
+        beneficiary: Option<ManagedAddress>,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        platform_fees: ManagedVec<BigUint>,
+        group_fees: ManagedVec<BigUint>,
+        deposit_id: ManagedBuffer,
+        max_allocation: Option<BigUint>,
+        ambassadors: MultiValueEncoded<MultiValue2<ManagedVec<BigUint>, ManagedAddress>>,
+        multi_payment: bool,
+    ) {
+        match self.pool_status() {
+            PoolStatus::Open => {}
+            PoolStatus::Scheduled => sc_panic!("Deposits not open yet"),
+            PoolStatus::Disabled => sc_panic!("Pool is not enabled"),
+            _ => sc_panic!("Deposits closed"),
+        }
+        let payments = self.deposit_payments();
+        require!(
+            platform_fees.len() == payments.len() && group_fees.len() == payments.len(),
+            "Fees must be provided for every payment"
+        );
 
         self.validate_deposit_signature(
             timestamp,
             &self.pool_id().get(),
-            &caller,
-            &platform_fee,
-            &group_fee,
-            signer,
+            payer,
+            &beneficiary,
+            &platform_fees,
+            &group_fees,
+            &payments,
+            &deposit_id,
+            &max_allocation,
             signature,
             ambassadors.clone(),
         );
+        require!(
+            self.deposits(&deposit_id).is_empty(),
+            "Deposit ID already used"
+        );
 
-        require!(self.is_registered(&caller), "Wallet not registered");
+        let depositor = beneficiary.clone().unwrap_or_else(|| payer.clone());
+        require!(self.is_registered(&depositor), "Wallet not registered");
         require!(
             ambassadors.len() <= 2,
             "Cannot have more than 2 ambassadors"
         );
-        require!(platform_fee > 0, "Platform fee cannot be zero");
-        require!(group_fee > 0, "Group fee cannot be zero");
 
-        let payment = self.call_value().single_esdt();
-        self.validate_deposit(&payment, &timestamp);
+        let mut ambassador_wallets: ManagedVec<ManagedAddress> = ManagedVec::new();
+        let mut ambassador_fees: ManagedVec<ManagedVec<BigUint>> = ManagedVec::new();
+        for ambassador in ambassadors.into_iter() {
+            let (fees, wallet) = ambassador.into_tuple();
+            require!(
+                fees.len() == payments.len(),
+                "Fees must be provided for every payment"
+            );
+            ambassador_fees.push(fees);
+            ambassador_wallets.push(wallet);
+        }
 
-        self.increase_general(&caller, &payment);
-        self.increase_totals(&payment.token_identifier, &payment.amount);
-        self.increase_platform_fee(&caller, &payment.token_identifier, &platform_fee);
-        self.increase_group_fee(&caller, &payment.token_identifier, &group_fee);
+        let mut net_amounts: ManagedVec<BigUint> = ManagedVec::new();
+        for (index, payment) in payments.iter().enumerate() {
+            let token = &payment.token_identifier;
+            let platform_fee = platform_fees.get(index).clone_value();
+            let group_fee = group_fees.get(index).clone_value();
+            require!(platform_fee > 0, "Platform fee cannot be zero");
+            require!(group_fee > 0, "Group fee cannot be zero");
 
-        let mut total_fees = platform_fee.clone() + group_fee.clone();
+            self.validate_deposit(token, &timestamp);
 
-        let mut user_ambassador_fee: Option<BigUint> = None;
-        let mut user_ambassador: Option<ManagedAddress> = None;
-        let mut group_ambassador_fee: Option<BigUint> = None;
-        let mut group_ambassador: Option<ManagedAddress> = None;
+            self.increase_general(&depositor, &payment);
+            self.increase_totals(token, &payment.amount);
+            self.increase_platform_fee(&depositor, token, &platform_fee);
+            self.increase_group_fee(&depositor, token, &group_fee);
 
-        for (index, ambassador) in ambassadors.into_iter().enumerate() {
-            let (ambassador_amount, ambassador_wallet) = ambassador.into_tuple();
-            self.increase_ambassador_fee(
-                &caller,
-                &payment.token_identifier,
-                &ambassador_amount,
-                &ambassador_wallet,
-            );
-            total_fees += &ambassador_amount;
-            if index == 0 {
-                user_ambassador_fee = Some(ambassador_amount);
-                user_ambassador = Some(ambassador_wallet);
-            } else if index == 1 {
-                group_ambassador_fee = Some(ambassador_amount);
-                group_ambassador = Some(ambassador_wallet);
+            let mut total_fees = platform_fee + group_fee;
+            for (ambassador_index, ambassador_wallet) in ambassador_wallets.iter().enumerate() {
+                let ambassador_fee = ambassador_fees
+                    .get(ambassador_index)
+                    .get(index)
+                    .clone_value();
+                self.increase_ambassador_fee(
+                    &depositor,
+                    token,
+                    &ambassador_fee,
+                    &ambassador_wallet,
+                );
+                total_fees += &ambassador_fee;
             }
+            net_amounts.push(self.denominate_payment(token, &(payment.amount - total_fees)));
         }
 
+        // All payments are accounted before checking the caps, so the deposit is either
+        // accepted or rejected as a whole.
         require!(
-            self.total_amount().get()
-                - self.total_ambassador_fee().get()
-                - self.total_group_fee().get()
-                - self.total_platform_fee().get()
-                <= self.hard_cap().get() * 10_u64.pow(DEFAULT_DECIMALS),
+            self.oversubscription_enabled().get()
+                || self.net_total_amount() <= self.hard_cap().get() * 10_u64.pow(DEFAULT_DECIMALS),
             "Hard cap threshold would be exceeded"
         );
 
-        let max_deposit_denominated = self.match_denomination(self.max_deposit().get(), &payment);
+        self.apply_wallet_max_allocation(&depositor, max_allocation);
         require!(
-            payment.amount.clone() - total_fees <= max_deposit_denominated,
+            self.net_deposited_amount(&depositor) <= self.max_deposit_denominated(&depositor),
             "Payment amount too high"
         );
+        self.validate_deposit_policy(&depositor, &net_amounts);
+        let mut net_amount = BigUint::zero();
+        for amount in net_amounts.iter() {
+            net_amount += &*amount;
+        }
+        let round_id = self.record_round_deposit(&depositor, &net_amount);
+
+        self.deposits(&deposit_id).set(Deposit {
+            depositor: depositor.clone(),
+            payer: payer.clone(),
+            payments: payments.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+            round: round_id,
+        });
+
+        if round_id > 0 {
+            self.round_deposit_event(
+                self.pool_id().get(),
+                deposit_id.clone(),
+                round_id,
+                depositor.clone(),
+                net_amount,
+                self.round_total(round_id).get(),
+            );
+        }
+
+        if beneficiary.is_some() {
+            self.deposited_for_event(
+                self.pool_id().get(),
+                deposit_id.clone(),
+                payer.clone(),
+                depositor.clone(),
+            );
+        }
 
+        self.update_pool_status();
+        // deposit and depositFor keep the single payment depositEvent indexers rely on.
+        if multi_payment {
+            self.deposited_multi_event(
+                self.pool_id().get(),
+                deposit_id,
+                depositor,
+                payments,
+                platform_fees,
+                group_fees,
+                ambassador_fees.try_get(0).map(|fees| fees.clone_value()),
+                ambassador_wallets
+                    .try_get(0)
+                    .map(|wallet| wallet.clone_value()),
+                ambassador_fees.try_get(1).map(|fees| fees.clone_value()),
+                ambassador_wallets
+                    .try_get(1)
+                    .map(|wallet| wallet.clone_value()),
+            );
+            return;
+        }
+        let payment = payments.get(0).clone();
         self.deposited_event(
             self.pool_id().get(),
             deposit_id,
-            caller,
+            depositor,
             payment.token_identifier,
             payment.amount,
-            platform_fee,
-            group_fee,
-            user_ambassador_fee,
-            user_ambassador,
-            group_ambassador_fee,
-            group_ambassador,
+            platform_fees.get(0).clone_value(),
+            group_fees.get(0).clone_value(),
+            ambassador_fees
+                .try_get(0)
+                .map(|fees| fees.get(0).clone_value()),
+            ambassador_wallets
+                .try_get(0)
+                .map(|wallet| wallet.clone_value()),
+            ambassador_fees
+                .try_get(1)
+                .map(|fees| fees.get(0).clone_value()),
+            ambassador_wallets
+                .try_get(1)
+                .map(|wallet| wallet.clone_value()),
         );
     }
 
     #[endpoint(refund)]
     fn refund(&self, timestamp: u64, signature: ManagedBuffer) -> OperationCompletionStatus {
-        self.validate_owner_call_on_enabled_pool(timestamp, signature);
-        require!(self.refund_enabled().get(), "Refunds are not enabled");
-        require!(
-            self.blockchain().get_block_timestamp() > self.end_date().get(),
-            "Refunds are not open"
-        );
-        require!(
-            self.total_amount().get() < self.soft_cap().get() * 10_u64.pow(DEFAULT_DECIMALS),
-            "Soft cap exceeded"
-        );
+        // cancelPool disables the pool, so only the regular refunds require an enabled pool.
+        if self.pool_status() == PoolStatus::Cancelled {
+            self.validate_owner_call(timestamp, signature, b"refund", &ManagedBuffer::new());
+        } else {
+            self.validate_owner_call_on_enabled_pool(
+                timestamp,
+                signature,
+                b"refund",
+                &ManagedBuffer::new(),
+            );
+            require!(self.refund_enabled().get(), "Refunds are not enabled");
+            self.require_pool_status(
+                &[PoolStatus::SoftCapFailed, PoolStatus::Refunding],
+                "Refunds are not open",
+            );
+        }
+        self.refund_started().set(true);
+        let result = self.refund_addresses();
+        self.update_pool_status();
+        result
+    }
 
+    fn refund_addresses(&self) -> OperationCompletionStatus {
         let addresses = self.addresses();
         let addresses_len = addresses.len();
         let addresses_iter = addresses.iter();
@@ -195,12 +467,13 @@ pub trait RaisePool:
             }
 
             let address = addresses_iter.next().clone().unwrap();
-            let mut payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+            let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
             for token_identifier in self.deposited_currencies(&address).iter() {
                 let payment = self.release_token_admin(&address, &token_identifier);
                 payments.push(payment);
             }
-            self.send().direct_multi(&address, &payments);
+            self.send_multi_if_not_empty(&address, &payments);
+            self.refund_event(self.pool_id().get(), address, payments);
             refund_index += 1;
             tx_index += 1;
         }
@@ -209,90 +482,323 @@ pub trait RaisePool:
         OperationCompletionStatus::Completed
     }
 
+    #[endpoint(claimRefund)]
+    fn claim_refund(&self) {
+        if self.pool_status() != PoolStatus::Cancelled {
+            require!(
+                self.blockchain().get_block_timestamp()
+                    > self.end_date().get() + REFUND_CLAIM_GRACE_PERIOD,
+                "Refund claims are not open"
+            );
+            self.require_pool_status(
+                &[PoolStatus::SoftCapFailed, PoolStatus::Refunding],
+                "Refund claims are not open",
+            );
+        }
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.deposited_currencies(&caller).is_empty(),
+            "Nothing to refund"
+        );
+
+        self.refund_started().set(true);
+        let payments = self.release_all_tokens_admin(&caller);
+        if self.refund_index().get() == 0 {
+            self.addresses().swap_remove(&caller);
+        }
+        self.update_pool_status();
+        self.send_multi_if_not_empty(&caller, &payments);
+        self.refund_event(self.pool_id().get(), caller, payments);
+    }
+
     #[endpoint(release)]
-    fn release(
-        &self,
-        timestamp: u64,
-        signature: ManagedBuffer,
-        overcommited: MultiValueEncoded<ManagedAddress>,
-    ) -> OperationCompletionStatus {
-        self.validate_owner_call(timestamp, signature);
+    fn release(&self, timestamp: u64, signature: ManagedBuffer) -> OperationCompletionStatus {
+        self.validate_high_value_owner_call(
+            timestamp,
+            signature,
+            b"release",
+            &ManagedBuffer::new(),
+        );
+        require!(
+            self.release_plan().is_empty() || self.release_plan_finalized().get(),
+            "Release plan not finalized"
+        );
+        self.require_pool_status(
+            &[PoolStatus::Closed, PoolStatus::Releasing],
+            "Pool cannot be released",
+        );
         self.raise_pool_enabled().set(false);
-        let overcommited_len = overcommited.len();
+        self.release_started().set(true);
+        let result = self.process_release();
+        self.update_pool_status();
+        result
+    }
+
+    fn process_release(&self) -> OperationCompletionStatus {
         loop {
             match self.release_state().get() {
                 ReleaseState::None => {
-                    if overcommited_len > 0 {
-                        let status =
-                            self.refund_overcommited(overcommited.clone(), overcommited_len);
+                    let status = self.refund_overcommited();
+                    if status == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                        return status;
+                    }
+                    if self.oversubscription_enabled().get() {
+                        let status = self.allocate_pro_rata();
                         if status == OperationCompletionStatus::InterruptedBeforeOutOfGas {
                             return status;
                         }
-                        self.release_state()
-                            .set(ReleaseState::OvercommitersReleased);
-                    } else {
-                        self.release_state()
-                            .set(ReleaseState::OvercommitersReleased);
                     }
+                    self.set_release_state(ReleaseState::OvercommitersReleased);
                 }
                 ReleaseState::OvercommitersReleased => {
-                    self.release_plaform();
-                    self.release_state().set(ReleaseState::PlatformReleased);
+                    if !self.fee_claim_mode().get() {
+                        self.release_plaform();
+                    }
+                    self.set_release_state(ReleaseState::PlatformReleased);
                 }
                 ReleaseState::PlatformReleased => {
-                    self.release_group();
-                    self.release_state().set(ReleaseState::GroupReleased);
+                    if !self.fee_claim_mode().get() {
+                        self.release_group();
+                    }
+                    self.set_release_state(ReleaseState::GroupReleased);
                 }
                 ReleaseState::GroupReleased => {
-                    let status = self.release_ambassadors();
-                    if status == OperationCompletionStatus::InterruptedBeforeOutOfGas {
-                        return status;
+                    if !self.fee_claim_mode().get() {
+                        let status = self.release_ambassadors();
+                        if status == OperationCompletionStatus::InterruptedBeforeOutOfGas {
+                            return status;
+                        }
                     }
-                    self.release_state().set(ReleaseState::AllReleased);
+                    self.set_release_state(ReleaseState::AllReleased);
                 }
                 ReleaseState::AllReleased => {
                     self.retrieve();
-                    self.release_state().set(ReleaseState::Retrieved);
+                    if self.fee_claim_mode().get() {
+                        self.fee_claim_deadline()
+                            .set(self.blockchain().get_block_timestamp() + FEE_CLAIM_PERIOD);
+                    }
+                    self.set_release_state(ReleaseState::Retrieved);
                 }
                 ReleaseState::Retrieved => return OperationCompletionStatus::Completed,
             }
         }
     }
 
+    fn set_release_state(&self, new_state: ReleaseState) {
+        let old_state = self.release_state().get();
+        self.release_state().set(new_state);
+        self.release_state_changed_event(self.pool_id().get(), old_state, new_state);
+    }
+
     fn retrieve(&self) {
         let caller = self.blockchain().get_caller();
         let mut payments = ManagedVec::new();
+        for token in self.payment_currencies().iter() {
+            let mut amount = self.total_amount_currency(&token).get();
+            if self.fee_claim_mode().get() {
+                amount -= self.unclaimed_fees(&token);
+            }
+            if amount > 0 {
+                payments.push(TokenPayment::new(token.clone(), amount));
+            }
+        }
+        self.send_multi_if_not_empty(&caller, &payments);
+        for payment in &payments {
+            self.decrease_totals(&payment.token_identifier, &payment.amount);
+        }
+        self.retrieve_event(self.pool_id().get(), caller, payments);
+    }
+
+    #[endpoint(setFeeClaimMode)]
+    fn set_fee_claim_mode(&self, timestamp: u64, signature: ManagedBuffer, value: bool) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &value);
+        self.validate_owner_call(timestamp, signature, b"setFeeClaimMode", &arguments);
+        require!(
+            !self.release_started().get(),
+            "Release in progress or already completed"
+        );
+        self.fee_claim_mode().set(value);
+        self.fee_claim_mode_event(self.pool_id().get(), value);
+    }
+
+    #[endpoint(claimFees)]
+    fn claim_fees(&self) {
+        require!(self.fee_claim_mode().get(), "Fee claims are not enabled");
+        require!(
+            self.release_state().get() == ReleaseState::Retrieved,
+            "Release not completed"
+        );
+        require!(!self.fees_swept().get(), "Unclaimed fees were swept");
+        let caller = self.blockchain().get_caller();
+        let payments = self.take_claimable_fees(&caller);
+        require!(!payments.is_empty(), "Nothing to claim");
+        for payment in &payments {
+            self.decrease_totals(&payment.token_identifier, &payment.amount);
+        }
+        self.send_multi_if_not_empty(&caller, &payments);
+        self.fees_claimed_event(self.pool_id().get(), caller, payments);
+    }
+
+    #[endpoint(sweepUnclaimedFees)]
+    fn sweep_unclaimed_fees(&self, timestamp: u64, signature: ManagedBuffer) {
+        self.validate_owner_call(
+            timestamp,
+            signature,
+            b"sweepUnclaimedFees",
+            &ManagedBuffer::new(),
+        );
+        require!(
+            self.release_state().get() == ReleaseState::Retrieved,
+            "Release not completed"
+        );
+        require!(!self.fees_swept().get(), "Unclaimed fees were swept");
+        require!(
+            self.blockchain().get_block_timestamp() > self.fee_claim_deadline().get(),
+            "Fee claim period not over"
+        );
+        let mut payments = ManagedVec::new();
         for token in self.payment_currencies().iter() {
             let amount = self.total_amount_currency(&token).get();
             if amount > 0 {
-                payments.push(EsdtTokenPayment::new(token.clone(), 0, amount));
+                payments.push(TokenPayment::new(token.clone(), amount));
             }
         }
-        self.send().direct_multi(&caller, &payments);
+        self.fees_swept().set(true);
+        let wallet = self.current_platform_fee_wallet();
+        self.send_multi_if_not_empty(&wallet, &payments);
         for payment in &payments {
             self.decrease_totals(&payment.token_identifier, &payment.amount);
         }
+        self.fees_swept_event(self.pool_id().get(), wallet, payments);
     }
 
     #[endpoint(userRefund)]
-    fn user_refund(&self, timestamp: u64, signature: ManagedBuffer, token: TokenIdentifier) {
+    fn user_refund(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        token: EgldOrEsdtTokenIdentifier,
+    ) {
+        require!(
+            self.pool_status() != PoolStatus::Cancelled,
+            "Pool is cancelled, use claimRefund"
+        );
+        require!(self.refund_enabled().get(), "Refund is not enabled");
+        require!(
+            self.refund_deadline().get() > self.blockchain().get_block_timestamp(),
+            "Refund deadline has passed"
+        );
+        let caller = self.blockchain().get_caller();
+        require!(self.is_registered(&caller), "Wallet not registered");
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &token);
+        self.validate_user_call(timestamp, signature, b"userRefund", &arguments);
+        let amount = self.release_token_user(&caller, &token);
+        self.update_pool_status();
+        self.send().direct(&caller, &token, 0, &amount);
+        self.user_refund_event(self.pool_id().get(), caller, token, amount);
+    }
+
+    #[endpoint(withdraw)]
+    fn withdraw(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &token);
+        self.encode_argument(&mut arguments, &amount);
+        self.validate_user_call(timestamp, signature, b"withdraw", &arguments);
         require!(self.refund_enabled().get(), "Refund is not enabled");
         require!(
             self.refund_deadline().get() > self.blockchain().get_block_timestamp(),
             "Refund deadline has passed"
         );
+        self.require_pool_status(
+            &[
+                PoolStatus::Scheduled,
+                PoolStatus::Open,
+                PoolStatus::Disabled,
+                PoolStatus::Closed,
+                PoolStatus::SoftCapFailed,
+            ],
+            "Withdrawals are closed",
+        );
         let caller = self.blockchain().get_caller();
         require!(self.is_registered(&caller), "Wallet not registered");
-        self.validate_user_refund_call(
+        require!(
+            amount > 0 && amount <= self.deposited_amount(&caller, &token).get(),
+            "Invalid withdraw amount"
+        );
+
+        let penalty = self.withdrawal_penalty(&caller, &token, &amount);
+        let payment = self.reduce_deposit(&caller, &token, &amount);
+        if self.deposited_currencies(&caller).is_empty() {
+            self.addresses().swap_remove(&caller);
+        } else {
+            self.validate_deposit_policy(&caller, &ManagedVec::new());
+        }
+
+        let refunded = &payment.amount - &penalty;
+        if penalty > 0 {
+            self.send()
+                .direct(&self.current_platform_fee_wallet(), &token, 0, &penalty);
+        }
+        if refunded > 0 {
+            self.send().direct(&caller, &token, 0, &refunded);
+        }
+        self.update_pool_status();
+        self.withdraw_event(
+            self.pool_id().get(),
+            caller,
+            token,
+            amount,
+            penalty,
+            refunded,
+        );
+    }
+
+    // Option::is_none_or is not available on the pinned toolchain.
+    #[allow(unknown_lints, clippy::unnecessary_map_or)]
+    #[endpoint(setWithdrawalPenaltySchedule)]
+    fn set_withdrawal_penalty_schedule(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        tiers: MultiValueEncoded<MultiValue2<u64, u64>>,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        for tier in tiers.clone() {
+            let (offset, penalty) = tier.into_tuple();
+            self.encode_argument(&mut arguments, &offset);
+            self.encode_argument(&mut arguments, &penalty);
+        }
+        self.validate_owner_call(
             timestamp,
-            &self.pool_id().get(),
-            &caller,
-            &token,
-            self.signer().get(),
             signature,
+            b"setWithdrawalPenaltySchedule",
+            &arguments,
         );
-        let amount = self.release_token_user(&caller, &token);
-        self.send().direct_esdt(&caller, &token, 0, &amount);
+
+        self.withdrawal_penalty_schedule().clear();
+        let mut schedule = ManagedVec::new();
+        let mut last_offset: Option<u64> = None;
+        for tier in tiers {
+            let (offset, penalty) = tier.into_tuple();
+            require!(
+                last_offset.map_or(true, |last| offset > last),
+                "Penalty tiers must be sorted by offset"
+            );
+            require!(penalty <= PENALTY_DENOMINATOR, "Invalid penalty");
+            let tier = PenaltyTier { offset, penalty };
+            self.withdrawal_penalty_schedule().push(&tier);
+            schedule.push(tier);
+            last_offset = Some(offset);
+        }
+        self.withdrawal_penalty_schedule_changed_event(self.pool_id().get(), schedule);
     }
 
     #[endpoint(adminRefund)]
@@ -302,16 +808,50 @@ pub trait RaisePool:
         signature: ManagedBuffer,
         addresses: MultiValueEncoded<ManagedAddress>,
     ) {
-        self.validate_owner_call(timestamp, signature);
+        let mut arguments = ManagedBuffer::new();
+        for address in addresses.clone() {
+            self.encode_argument(&mut arguments, &address);
+        }
+        self.validate_high_value_owner_call(timestamp, signature, b"adminRefund", &arguments);
         for address in addresses {
-            let mut payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+            let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
             for token in self.deposited_currencies(&address).iter() {
                 let payment = self.release_token_admin(&address, &token);
                 payments.push(payment);
             }
             self.addresses().swap_remove(&address);
-            self.send().direct_multi(&address, &payments);
+            self.send_multi_if_not_empty(&address, &payments);
+            self.admin_refund_event(self.pool_id().get(), address, payments);
         }
+        self.update_pool_status();
+    }
+
+    #[endpoint(cancelPool)]
+    fn cancel_pool(&self, timestamp: u64, signature: ManagedBuffer) {
+        self.validate_high_value_owner_call(
+            timestamp,
+            signature,
+            b"cancelPool",
+            &ManagedBuffer::new(),
+        );
+        self.require_pool_status(
+            &[
+                PoolStatus::Scheduled,
+                PoolStatus::Open,
+                PoolStatus::Disabled,
+                PoolStatus::Closed,
+                PoolStatus::SoftCapFailed,
+                PoolStatus::Refunding,
+            ],
+            "Pool cannot be cancelled",
+        );
+        self.pool_cancelled().set(true);
+        self.raise_pool_enabled().set(false);
+        self.pool_cancelled_event(
+            self.pool_id().get(),
+            self.blockchain().get_block_timestamp(),
+        );
+        self.update_pool_status();
     }
 
     #[endpoint(setPlatformFeeWallet)]
@@ -321,18 +861,26 @@ pub trait RaisePool:
         signature: ManagedBuffer,
         wallet: ManagedAddress,
     ) {
-        self.validate_owner_call(timestamp, signature);
-        self.platform_fee_wallet().set(wallet);
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &wallet);
+        self.validate_owner_call(timestamp, signature, b"setPlatformFeeWallet", &arguments);
+        self.platform_fee_wallet().set(&wallet);
+        self.platform_fee_wallet_changed_event(self.pool_id().get(), wallet);
     }
 
     #[endpoint(enableRaisePool)]
     fn enable_raise_pool(&self, value: bool, timestamp: u64, signature: ManagedBuffer) {
-        self.validate_owner_call(timestamp, signature);
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &value);
+        self.validate_owner_call(timestamp, signature, b"enableRaisePool", &arguments);
         require!(
-            self.release_state().get() == ReleaseState::None,
+            !self.release_started().get(),
             "Release in progress or already completed, cannot enable pool"
         );
+        require!(!self.pool_cancelled().get(), "Pool is cancelled");
         self.raise_pool_enabled().set(value);
+        self.raise_pool_enabled_event(self.pool_id().get(), value);
+        self.update_pool_status();
     }
 
     #[endpoint(setTimestamps)]
@@ -344,7 +892,16 @@ pub trait RaisePool:
         new_end_date: u64,
         new_refund_deadline: u64,
     ) {
-        self.validate_owner_call_on_enabled_pool(timestamp, signature);
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &new_start_date);
+        self.encode_argument(&mut arguments, &new_end_date);
+        self.encode_argument(&mut arguments, &new_refund_deadline);
+        self.validate_owner_call_on_enabled_pool(
+            timestamp,
+            signature,
+            b"setTimestamps",
+            &arguments,
+        );
         require!(
             new_start_date < new_refund_deadline && new_refund_deadline < new_end_date,
             "Invalid timestamps"
@@ -352,6 +909,10 @@ pub trait RaisePool:
         self.start_date().set(new_start_date);
         self.refund_deadline().set(new_refund_deadline);
         self.end_date().set(new_end_date);
+        for (index, round) in self.rounds().iter().enumerate() {
+            self.validate_round(&round, index + 1);
+        }
+        self.update_pool_status();
         self.changed_timestamp_event(
             self.pool_id().get(),
             new_start_date,
@@ -362,22 +923,319 @@ pub trait RaisePool:
 
     #[endpoint(setRefundEnabled)]
     fn set_refund_enabled(&self, timestamp: u64, signature: ManagedBuffer, value: bool) {
-        self.validate_owner_call(timestamp, signature);
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &value);
+        self.validate_owner_call(timestamp, signature, b"setRefundEnabled", &arguments);
         self.refund_enabled().set(value);
+        self.refund_enabled_event(self.pool_id().get(), value);
+    }
+
+    #[endpoint(setDepositPolicy)]
+    fn set_deposit_policy(&self, timestamp: u64, signature: ManagedBuffer, policy: DepositPolicy) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &policy);
+        self.validate_owner_call(timestamp, signature, b"setDepositPolicy", &arguments);
+        self.require_pool_status(
+            &[PoolStatus::Scheduled],
+            "Deposit policy can only be changed before the sale starts",
+        );
+        self.validate_deposit_policy_config(policy);
+        self.deposit_policy().set(policy);
+        self.deposit_policy_changed_event(self.pool_id().get(), policy);
+    }
+
+    #[endpoint(addRound)]
+    fn add_round(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        name: ManagedBuffer,
+        start_date: u64,
+        end_date: u64,
+        min_deposit: BigUint,
+        max_deposit: BigUint,
+        cap: BigUint,
+        eligibility: RoundEligibility,
+    ) -> usize {
+        let round = Round {
+            name,
+            start_date,
+            end_date,
+            min_deposit,
+            max_deposit,
+            cap,
+            eligibility,
+        };
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &round);
+        self.validate_owner_call(timestamp, signature, b"addRound", &arguments);
+        require!(
+            !self.release_started().get(),
+            "Release in progress or already completed"
+        );
+        self.validate_round_change(&round, 0);
+
+        let round_id = self.rounds().push(&round);
+        self.round_configured_event(
+            self.pool_id().get(),
+            round_id,
+            round.name,
+            round.start_date,
+            round.end_date,
+            round.cap,
+        );
+        round_id
+    }
+
+    #[endpoint(updateRound)]
+    fn update_round(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        round_id: usize,
+        name: ManagedBuffer,
+        start_date: u64,
+        end_date: u64,
+        min_deposit: BigUint,
+        max_deposit: BigUint,
+        cap: BigUint,
+        eligibility: RoundEligibility,
+    ) {
+        let round = Round {
+            name,
+            start_date,
+            end_date,
+            min_deposit,
+            max_deposit,
+            cap,
+            eligibility,
+        };
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &round_id);
+        self.encode_argument(&mut arguments, &round);
+        self.validate_owner_call(timestamp, signature, b"updateRound", &arguments);
+        require!(
+            round_id > 0 && round_id <= self.rounds().len(),
+            "Invalid round"
+        );
+        require!(
+            self.blockchain().get_block_timestamp() < self.rounds().get(round_id).start_date,
+            "Round already started"
+        );
+        self.validate_round_change(&round, round_id);
+
+        self.rounds().set(round_id, &round);
+        self.round_configured_event(
+            self.pool_id().get(),
+            round_id,
+            round.name,
+            round.start_date,
+            round.end_date,
+            round.cap,
+        );
+    }
+
+    #[endpoint(addToRoundWhitelist)]
+    fn add_to_round_whitelist(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        round_id: usize,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &round_id);
+        for address in addresses.clone() {
+            self.encode_argument(&mut arguments, &address);
+        }
+        self.validate_owner_call(timestamp, signature, b"addToRoundWhitelist", &arguments);
+        require!(
+            round_id > 0 && round_id <= self.rounds().len(),
+            "Invalid round"
+        );
+        let mut added = ManagedVec::new();
+        for address in addresses {
+            if self.round_whitelist(round_id).insert(address.clone()) {
+                added.push(address);
+            }
+        }
+        self.round_whitelist_added_event(self.pool_id().get(), round_id, added);
+    }
+
+    #[endpoint(removeFromRoundWhitelist)]
+    fn remove_from_round_whitelist(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        round_id: usize,
+        addresses: MultiValueEncoded<ManagedAddress>,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &round_id);
+        for address in addresses.clone() {
+            self.encode_argument(&mut arguments, &address);
+        }
+        self.validate_owner_call(
+            timestamp,
+            signature,
+            b"removeFromRoundWhitelist",
+            &arguments,
+        );
+        require!(
+            round_id > 0 && round_id <= self.rounds().len(),
+            "Invalid round"
+        );
+        let mut removed = ManagedVec::new();
+        for address in addresses {
+            if self.round_whitelist(round_id).swap_remove(&address) {
+                removed.push(address);
+            }
+        }
+        self.round_whitelist_removed_event(self.pool_id().get(), round_id, removed);
+    }
+
+    #[endpoint(setUseFactoryConfig)]
+    fn set_use_factory_config(&self, timestamp: u64, signature: ManagedBuffer, value: bool) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &value);
+        self.validate_high_value_owner_call(
+            timestamp,
+            signature,
+            b"setUseFactoryConfig",
+            &arguments,
+        );
+        require!(
+            !self.factory_address().is_empty(),
+            "Pool was not deployed by a factory"
+        );
+        if value {
+            // The local values are only dropped once the factory can supply all of them.
+            let factory_address = self.factory_address().get();
+            let signer: ManagedAddress = self
+                .factory_proxy(factory_address.clone())
+                .signer()
+                .execute_on_dest_context();
+            let wallet_database_address: ManagedAddress = self
+                .factory_proxy(factory_address.clone())
+                .wallet_database_address()
+                .execute_on_dest_context();
+            let platform_fee_wallet: ManagedAddress = self
+                .factory_proxy(factory_address)
+                .default_platform_fee_wallet()
+                .execute_on_dest_context();
+            require!(
+                !signer.is_zero()
+                    && !wallet_database_address.is_zero()
+                    && !platform_fee_wallet.is_zero(),
+                "Factory configuration incomplete"
+            );
+            self.signer().clear();
+            self.wallet_database_address().clear();
+            self.platform_fee_wallet().clear();
+        } else {
+            self.signer().set(self.current_signer());
+            self.wallet_database_address()
+                .set(self.current_wallet_database_address());
+            self.platform_fee_wallet()
+                .set(self.current_platform_fee_wallet());
+        }
+        self.use_factory_config().set(value);
+        self.use_factory_config_event(self.pool_id().get(), value);
+    }
+
+    #[endpoint(rotateSigner)]
+    fn rotate_signer(
+        &self,
+        timestamp: u64,
+        signature: ManagedBuffer,
+        new_signer: ManagedAddress,
+        grace_period: u64,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &new_signer);
+        self.encode_argument(&mut arguments, &grace_period);
+        self.validate_high_value_owner_call(timestamp, signature, b"rotateSigner", &arguments);
+        require!(
+            grace_period <= MAX_SIGNER_GRACE_PERIOD,
+            "Grace period too long"
+        );
+        let old_signer = self.current_signer();
+        require!(new_signer != old_signer, "Signer already set");
+        require!(
+            !self.signers().contains(&new_signer),
+            "Signer already registered"
+        );
+        let grace_period_end = self.blockchain().get_block_timestamp() + grace_period;
+        self.previous_signer().set(&old_signer);
+        self.previous_signer_expiry().set(grace_period_end);
+        self.signer().set(&new_signer);
+        self.signer_rotated_event(
+            self.pool_id().get(),
+            old_signer,
+            new_signer,
+            grace_period_end,
+        );
+    }
+
+    #[endpoint(addSigner)]
+    fn add_signer(&self, timestamp: u64, signature: ManagedBuffer, signer: ManagedAddress) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &signer);
+        self.validate_high_value_owner_call(timestamp, signature, b"addSigner", &arguments);
+        require!(signer != self.current_signer(), "Signer already set");
+        require!(
+            self.signers().insert(signer.clone()),
+            "Signer already registered"
+        );
+        self.signer_added_event(self.pool_id().get(), signer);
+    }
+
+    #[endpoint(removeSigner)]
+    fn remove_signer(&self, timestamp: u64, signature: ManagedBuffer, signer: ManagedAddress) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &signer);
+        self.validate_high_value_owner_call(timestamp, signature, b"removeSigner", &arguments);
+        require!(self.signers().swap_remove(&signer), "Signer not registered");
+        require!(
+            self.signer_threshold().get() <= self.signers().len() + 1,
+            "Threshold higher than the number of signers"
+        );
+        self.signer_removed_event(self.pool_id().get(), signer);
+    }
+
+    #[endpoint(setSignerThreshold)]
+    fn set_signer_threshold(&self, timestamp: u64, signature: ManagedBuffer, threshold: usize) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &threshold);
+        self.validate_high_value_owner_call(
+            timestamp,
+            signature,
+            b"setSignerThreshold",
+            &arguments,
+        );
+        require!(threshold > 0, "Threshold cannot be zero");
+        require!(
+            threshold <= self.signers().len() + 1,
+            "Threshold higher than the number of signers"
+        );
+        self.signer_threshold().set(threshold);
+        self.signer_threshold_changed_event(self.pool_id().get(), threshold);
     }
 
     fn release_plaform(&self) {
-        let mut payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+        let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
         for token in self.payment_currencies().iter() {
             let fee = self.platform_fee(&token).get();
             if fee > 0 {
-                payments.push(EsdtTokenPayment::new(token, 0, fee));
+                payments.push(TokenPayment::new(token, fee));
             }
         }
-        self.send_multi_if_not_empty(&self.platform_fee_wallet().get(), &payments);
+        let wallet = self.current_platform_fee_wallet();
+        self.send_multi_if_not_empty(&wallet, &payments);
         for payment in &payments {
             self.decrease_totals(&payment.token_identifier, &payment.amount);
         }
+        self.platform_fees_released_event(self.pool_id().get(), wallet, payments);
     }
 
     fn release_group(&self) {
@@ -385,13 +1243,15 @@ pub trait RaisePool:
         for token in self.payment_currencies().iter() {
             let fee = self.group_fee(&token).get();
             if fee > 0 {
-                payments.push(EsdtTokenPayment::new(token.clone(), 0, fee));
+                payments.push(TokenPayment::new(token.clone(), fee));
             }
         }
-        self.send_multi_if_not_empty(&self.group_fee_wallet().get(), &payments);
+        let wallet = self.group_fee_wallet().get();
+        self.send_multi_if_not_empty(&wallet, &payments);
         for payment in &payments {
             self.decrease_totals(&payment.token_identifier, &payment.amount);
         }
+        self.group_fees_released_event(self.pool_id().get(), wallet, payments);
     }
 
     fn release_ambassadors(&self) -> OperationCompletionStatus {
@@ -411,23 +1271,20 @@ pub trait RaisePool:
                 return OperationCompletionStatus::InterruptedBeforeOutOfGas;
             }
             let ambassador = ambassadors_iter.next().unwrap();
-            let mut payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
+            let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
             for token_identifier in self.ambassador_currencies(&ambassador).iter() {
                 let amount = self
                     .referral_ambassador_fee(&ambassador, &token_identifier)
                     .get();
                 if amount > 0 {
-                    payments.push(EsdtTokenPayment::new(
-                        token_identifier.clone(),
-                        0,
-                        amount.clone(),
-                    ));
+                    payments.push(TokenPayment::new(token_identifier.clone(), amount.clone()));
                 }
             }
             self.send_multi_if_not_empty(&ambassador, &payments);
             for payment in &payments {
                 self.decrease_totals(&payment.token_identifier, &payment.amount);
             }
+            self.ambassador_fees_released_event(self.pool_id().get(), ambassador, payments);
             release_ambassador_index += 1;
             tx_index += 1;
         }
@@ -436,14 +1293,150 @@ pub trait RaisePool:
         OperationCompletionStatus::Completed
     }
 
-    fn refund_overcommited(
+    fn allocate_pro_rata(&self) -> OperationCompletionStatus {
+        let hard_cap = self.hard_cap().get() * 10_u64.pow(DEFAULT_DECIMALS);
+        if self.allocation_total().is_empty() {
+            let net_total = self.net_total_amount();
+            if net_total <= hard_cap {
+                return OperationCompletionStatus::Completed;
+            }
+            self.allocation_total().set(net_total);
+        }
+        let allocation_total = self.allocation_total().get();
+
+        let addresses = self.addresses();
+        let addresses_len = addresses.len();
+        let mut allocation_index = self.allocation_index().get();
+        let mut addresses_iter = addresses.iter().skip(allocation_index);
+        let mut tx_index = 0;
+
+        while allocation_index < addresses_len {
+            if self.blockchain().get_gas_left() < MIN_GAS_FOR_OPERATION
+                || tx_index == MAX_TX_PER_RELEASE
+            {
+                self.allocation_index().set(allocation_index);
+                return OperationCompletionStatus::InterruptedBeforeOutOfGas;
+            }
+            let address = addresses_iter.next().unwrap();
+            let tokens: ManagedVec<EgldOrEsdtTokenIdentifier> =
+                self.deposited_currencies(&address).iter().collect();
+            let mut payments: ManagedVec<TokenPayment<Self::Api>> = ManagedVec::new();
+            for token in tokens.iter() {
+                let deposited = self.deposited_amount(&address, &token).get();
+                let kept = &deposited * &hard_cap / &allocation_total;
+                let payment = self.reduce_deposit(&address, &token, &(deposited - kept));
+                if payment.amount > 0 {
+                    payments.push(payment);
+                }
+            }
+            let allocation = self.net_deposited_amount(&address);
+            self.allocations(&address).set(Allocation {
+                allocation: allocation.clone(),
+                excess_refund: payments.clone(),
+            });
+            self.send_multi_if_not_empty(&address, &payments);
+            self.allocation_refund_event(self.pool_id().get(), address, allocation, payments);
+            allocation_index += 1;
+            tx_index += 1;
+        }
+        self.allocation_index().set(addresses_len);
+        OperationCompletionStatus::Completed
+    }
+
+    #[endpoint(setOversubscriptionEnabled)]
+    fn set_oversubscription_enabled(&self, timestamp: u64, signature: ManagedBuffer, value: bool) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &value);
+        self.validate_owner_call(
+            timestamp,
+            signature,
+            b"setOversubscriptionEnabled",
+            &arguments,
+        );
+        require!(
+            !self.release_started().get(),
+            "Release in progress or already completed"
+        );
+        self.oversubscription_enabled().set(value);
+        self.oversubscription_enabled_event(self.pool_id().get(), value);
+    }
+
+    #[endpoint(submitReleasePlan)]
+    fn submit_release_plan(
         &self,
-        overcommited: MultiValueEncoded<ManagedAddress>,
-        overcommited_len: usize,
-    ) -> OperationCompletionStatus {
-        let overcommited_iter = overcommited.into_iter();
+        timestamp: u64,
+        signature: ManagedBuffer,
+        is_final: bool,
+        overcommited: MultiValueEncoded<
+            MultiValue3<ManagedAddress, EgldOrEsdtTokenIdentifier, BigUint>,
+        >,
+    ) {
+        let mut arguments = ManagedBuffer::new();
+        self.encode_argument(&mut arguments, &is_final);
+        let mut chunk = ManagedBuffer::new();
+        for overcommit in overcommited.clone() {
+            let (address, token, amount) = overcommit.into_tuple();
+            self.encode_argument(&mut chunk, &address);
+            self.encode_argument(&mut chunk, &token);
+            self.encode_argument(&mut chunk, &amount);
+        }
+        arguments.append(&chunk);
+        self.validate_high_value_owner_call(timestamp, signature, b"submitReleasePlan", &arguments);
+        require!(
+            !self.release_started().get(),
+            "Release in progress or already completed"
+        );
+        require!(
+            !self.release_plan_finalized().get(),
+            "Release plan already finalized"
+        );
+
+        for overcommit in overcommited {
+            let (address, token, amount) = overcommit.into_tuple();
+            self.release_plan().push(&OvercommitEntry {
+                address,
+                token,
+                amount,
+            });
+        }
+        let mut hash_input = ManagedBuffer::new();
+        if !self.release_plan_hash().is_empty() {
+            hash_input.append(self.release_plan_hash().get().as_managed_buffer());
+        }
+        hash_input.append(&chunk);
+        let plan_hash = self.crypto().keccak256(&hash_input);
+        self.release_plan_hash().set(&plan_hash);
+        self.release_plan_finalized().set(is_final);
+        self.release_plan_submitted_event(
+            self.pool_id().get(),
+            self.release_plan().len(),
+            plan_hash,
+            is_final,
+        );
+    }
+
+    #[endpoint(clearReleasePlan)]
+    fn clear_release_plan(&self, timestamp: u64, signature: ManagedBuffer) {
+        self.validate_high_value_owner_call(
+            timestamp,
+            signature,
+            b"clearReleasePlan",
+            &ManagedBuffer::new(),
+        );
+        require!(
+            !self.release_started().get(),
+            "Release in progress or already completed"
+        );
+        self.release_plan().clear();
+        self.release_plan_hash().clear();
+        self.release_plan_finalized().clear();
+        self.release_plan_cleared_event(self.pool_id().get());
+    }
+
+    fn refund_overcommited(&self) -> OperationCompletionStatus {
+        let release_plan = self.release_plan();
+        let overcommited_len = release_plan.len();
         let mut overcommited_index = self.overcommited_index().get();
-        let mut overcommited_iter = overcommited_iter.skip(overcommited_index);
         let mut tx_index: usize = 0;
 
         while overcommited_index < overcommited_len {
@@ -453,16 +1446,27 @@ pub trait RaisePool:
                 self.overcommited_index().set(overcommited_index);
                 return OperationCompletionStatus::InterruptedBeforeOutOfGas;
             }
-            let mut payments: ManagedVec<EsdtTokenPayment> = ManagedVec::new();
-            let address = overcommited_iter.next().unwrap();
-            for token in self.deposited_currencies(&address).iter() {
-                let payment = self.release_token_admin(&address, &token);
+            let OvercommitEntry {
+                address,
+                token,
+                amount,
+            } = release_plan.get(overcommited_index + 1);
+            if self.deposited_currencies(&address).contains(&token) {
+                let payment = self.reduce_deposit(&address, &token, &amount);
+                if self.deposited_currencies(&address).is_empty() {
+                    self.addresses().swap_remove(&address);
+                }
                 if payment.amount > 0 {
-                    payments.push(payment);
+                    self.send()
+                        .direct(&address, &payment.token_identifier, 0, &payment.amount);
+                    self.overcommit_refund_event(
+                        self.pool_id().get(),
+                        address,
+                        payment.token_identifier,
+                        payment.amount,
+                    );
                 }
             }
-            self.addresses().swap_remove(&address);
-            self.send_multi_if_not_empty(&address, &payments);
             overcommited_index += 1;
             tx_index += 1;
         }
@@ -0,0 +1,127 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use curve25519_dalek::{edwards::CompressedEdwardsY, edwards::EdwardsPoint, scalar::Scalar};
+use sha2::{Digest, Sha512};
+
+pub const ED25519_SIGNATURE_LENGTH: usize = 64;
+pub const SIGNER_SIGNATURE_LENGTH: usize = 32 + ED25519_SIGNATURE_LENGTH;
+const PAYLOAD_CHUNK_LENGTH: usize = 256;
+
+#[multiversx_sc::module]
+pub trait SignersModule: crate::storage::StorageModule + crate::config::ConfigModule {
+    // A plain 64 bytes signature is accepted from the current signer and, while its grace
+    // period runs, from the previous signer, so backends can switch keys at their own pace.
+    // A failed VM ed25519 check aborts the transaction, so the previous signer is checked
+    // in contract code first and the current signer by the VM. Additional signers have to
+    // be passed as a sequence of (signer_address + signature) chunks. The previous signer
+    // does not count for high value calls.
+    fn verify_backend_signature(
+        &self,
+        payload: &ManagedBuffer,
+        signature: &ManagedBuffer,
+        threshold: usize,
+        high_value: bool,
+    ) {
+        if signature.len() == ED25519_SIGNATURE_LENGTH {
+            require!(threshold <= 1, "Not enough signatures");
+            if !high_value
+                && self.previous_signer_active()
+                && verify_ed25519_in_contract(&self.previous_signer().get(), payload, signature)
+            {
+                return;
+            }
+            self.crypto().verify_ed25519(
+                self.current_signer().as_managed_buffer(),
+                payload,
+                signature,
+            );
+            return;
+        }
+
+        require!(
+            !signature.is_empty() && signature.len() % SIGNER_SIGNATURE_LENGTH == 0,
+            "Invalid signature format"
+        );
+        let signatures_count = signature.len() / SIGNER_SIGNATURE_LENGTH;
+        require!(signatures_count >= threshold, "Not enough signatures");
+
+        let mut used_signers: ManagedVec<ManagedAddress> = ManagedVec::new();
+        for index in 0..signatures_count {
+            let offset = index * SIGNER_SIGNATURE_LENGTH;
+            let signer_buffer = signature.copy_slice(offset, 32).unwrap();
+            let signer_signature = signature
+                .copy_slice(offset + 32, ED25519_SIGNATURE_LENGTH)
+                .unwrap();
+            let signer = ManagedAddress::try_from(signer_buffer).unwrap();
+            require!(
+                self.is_active_signer(&signer, !high_value),
+                "Signer not authorized"
+            );
+            require!(!used_signers.contains(&signer), "Duplicate signer");
+            self.crypto()
+                .verify_ed25519(signer.as_managed_buffer(), payload, &signer_signature);
+            used_signers.push(signer);
+        }
+    }
+
+    fn is_active_signer(&self, address: &ManagedAddress, include_previous_signer: bool) -> bool {
+        if *address == self.current_signer() || self.signers().contains(address) {
+            return true;
+        }
+        include_previous_signer
+            && self.previous_signer_active()
+            && *address == self.previous_signer().get()
+    }
+
+    fn previous_signer_active(&self) -> bool {
+        !self.previous_signer().is_empty()
+            && self.blockchain().get_block_timestamp() < self.previous_signer_expiry().get()
+    }
+
+    fn required_signatures(&self) -> usize {
+        core::cmp::max(self.signer_threshold().get(), 1)
+    }
+}
+
+// RFC 8032 verification, R == [S]B - [H(R + A + M)]A, returning false instead of aborting.
+// The payload is hashed in chunks since it has no size limit.
+fn verify_ed25519_in_contract<M: ManagedTypeApi>(
+    key: &ManagedAddress<M>,
+    payload: &ManagedBuffer<M>,
+    signature: &ManagedBuffer<M>,
+) -> bool {
+    let key_bytes = key.to_byte_array();
+    let Some(public_key) = CompressedEdwardsY(key_bytes).decompress() else {
+        return false;
+    };
+    let mut signature_bytes = [0u8; ED25519_SIGNATURE_LENGTH];
+    if signature.load_slice(0, &mut signature_bytes).is_err() {
+        return false;
+    }
+    let mut r_bytes = [0u8; 32];
+    r_bytes.copy_from_slice(&signature_bytes[..32]);
+    let mut s_bytes = [0u8; 32];
+    s_bytes.copy_from_slice(&signature_bytes[32..]);
+    let Some(s) = Scalar::from_canonical_bytes(s_bytes) else {
+        return false;
+    };
+
+    let mut hasher = Sha512::new();
+    hasher.update(r_bytes);
+    hasher.update(key_bytes);
+    let mut chunk = [0u8; PAYLOAD_CHUNK_LENGTH];
+    let mut offset = 0;
+    while offset < payload.len() {
+        let length = core::cmp::min(PAYLOAD_CHUNK_LENGTH, payload.len() - offset);
+        if payload.load_slice(offset, &mut chunk[..length]).is_err() {
+            return false;
+        }
+        hasher.update(&chunk[..length]);
+        offset += length;
+    }
+    let k = Scalar::from_hash(hasher);
+
+    let r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, &-public_key, &s);
+    r.compress().to_bytes() == r_bytes
+}
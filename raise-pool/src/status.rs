@@ -0,0 +1,55 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::helper::DEFAULT_DECIMALS;
+use crate::storage::{PoolStatus, ReleaseState};
+
+#[multiversx_sc::module]
+pub trait StatusModule: crate::storage::StorageModule + crate::events::EventsModule {
+    #[view(getPoolStatus)]
+    fn pool_status(&self) -> PoolStatus {
+        if self.pool_cancelled().get() {
+            return PoolStatus::Cancelled;
+        }
+        if self.release_state().get() == ReleaseState::Retrieved {
+            return PoolStatus::Released;
+        }
+        if self.release_started().get() {
+            return PoolStatus::Releasing;
+        }
+
+        let timestamp = self.blockchain().get_block_timestamp();
+        if timestamp >= self.end_date().get() {
+            if self.total_amount().get() >= self.soft_cap().get() * 10_u64.pow(DEFAULT_DECIMALS) {
+                return PoolStatus::Closed;
+            }
+            if self.refund_started().get() {
+                return PoolStatus::Refunding;
+            }
+            return PoolStatus::SoftCapFailed;
+        }
+        if timestamp <= self.start_date().get() {
+            return PoolStatus::Scheduled;
+        }
+        if !self.raise_pool_enabled().get() {
+            return PoolStatus::Disabled;
+        }
+        PoolStatus::Open
+    }
+
+    // Time based transitions are only observed by the contract on the next call, so the
+    // event is emitted by the first endpoint that sees the new status.
+    fn update_pool_status(&self) {
+        let status = self.pool_status();
+        let last_status = self.last_pool_status().get();
+        if status != last_status {
+            self.last_pool_status().set(status);
+            self.pool_status_changed_event(self.pool_id().get(), last_status, status);
+        }
+    }
+
+    fn require_pool_status(&self, allowed: &[PoolStatus], error: &str) {
+        let status = self.pool_status();
+        require!(allowed.contains(&status), error);
+    }
+}
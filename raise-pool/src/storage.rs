@@ -1,7 +1,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TopEncode, TopDecode, PartialEq, TypeAbi, Clone, Copy, Debug)]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
 pub enum ReleaseState {
     None,
     OvercommitersReleased,
@@ -11,6 +13,242 @@ pub enum ReleaseState {
     Retrieved,
 }
 
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum PoolStatus {
+    Scheduled,
+    Open,
+    Closed,
+    SoftCapFailed,
+    Refunding,
+    Releasing,
+    Released,
+    Cancelled,
+    Disabled,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum DepositPolicy {
+    Off,
+    PerDeposit,
+    CumulativePerWallet,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, TypeAbi, Clone, Copy, Debug,
+)]
+pub enum RoundEligibility {
+    Everyone,
+    Whitelist,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct Round<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub min_deposit: BigUint<M>,
+    pub max_deposit: BigUint<M>,
+    pub cap: BigUint<M>,
+    pub eligibility: RoundEligibility,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone, Debug,
+)]
+pub struct PenaltyTier {
+    pub offset: u64,
+    pub penalty: u64,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct DepositTranche<M: ManagedTypeApi> {
+    pub timestamp: u64,
+    pub amount: BigUint<M>,
+}
+
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, ManagedVecItem, Clone, Debug,
+)]
+pub struct OvercommitEntry<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub token: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+// EgldOrEsdtTokenPayment cannot be stored in a ManagedVec, so payments that may be
+// in EGLD are kept in this type instead.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, TypeAbi, Clone, Debug,
+)]
+pub struct TokenPayment<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> TokenPayment<M> {
+    pub fn new(token_identifier: EgldOrEsdtTokenIdentifier<M>, amount: BigUint<M>) -> Self {
+        TokenPayment {
+            token_identifier,
+            amount,
+        }
+    }
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, Clone, Debug)]
+pub struct Allocation<M: ManagedTypeApi> {
+    pub allocation: BigUint<M>,
+    pub excess_refund: ManagedVec<M, TokenPayment<M>>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Debug)]
+pub struct Deposit<M: ManagedTypeApi> {
+    pub depositor: ManagedAddress<M>,
+    pub payer: ManagedAddress<M>,
+    pub payments: ManagedVec<M, TokenPayment<M>>,
+    pub timestamp: u64,
+    pub round: usize,
+}
+
+#[derive(TopEncode, NestedEncode, ManagedVecItem, TypeAbi, Clone, Debug)]
+pub struct CurrencyInfo<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub decimals: u32,
+    pub total_amount: BigUint<M>,
+    pub platform_fee: BigUint<M>,
+    pub group_fee: BigUint<M>,
+    pub ambassador_fee: BigUint<M>,
+}
+
+#[derive(TopEncode, TypeAbi, Clone, Debug)]
+pub struct PoolInfo<M: ManagedTypeApi> {
+    pub pool_id: ManagedBuffer<M>,
+    pub owner: ManagedAddress<M>,
+    pub status: PoolStatus,
+    pub release_state: ReleaseState,
+    pub enabled: bool,
+    pub soft_cap: BigUint<M>,
+    pub hard_cap: BigUint<M>,
+    pub min_deposit: BigUint<M>,
+    pub max_deposit: BigUint<M>,
+    pub deposit_increments: BigUint<M>,
+    pub deposit_policy: DepositPolicy,
+    pub start_date: u64,
+    pub end_date: u64,
+    pub refund_enabled: bool,
+    pub refund_deadline: u64,
+    pub oversubscription_enabled: bool,
+    pub fee_claim_mode: bool,
+    pub platform_fee_wallet: ManagedAddress<M>,
+    pub group_fee_wallet: ManagedAddress<M>,
+    pub total_amount: BigUint<M>,
+    pub total_platform_fee: BigUint<M>,
+    pub total_group_fee: BigUint<M>,
+    pub total_ambassador_fee: BigUint<M>,
+    pub depositors_count: usize,
+    pub ambassadors_count: usize,
+    pub rounds_count: usize,
+    pub currencies: ManagedVec<M, CurrencyInfo<M>>,
+}
+
+#[derive(TopEncode, NestedEncode, ManagedVecItem, TypeAbi, Clone, Debug)]
+pub struct DepositorCurrencyInfo<M: ManagedTypeApi> {
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub deposited_amount: BigUint<M>,
+    pub platform_fee: BigUint<M>,
+    pub group_fee: BigUint<M>,
+    pub ambassador_fee: BigUint<M>,
+}
+
+#[derive(TopEncode, NestedEncode, ManagedVecItem, TypeAbi, Clone, Debug)]
+pub struct AmbassadorFeeInfo<M: ManagedTypeApi> {
+    pub ambassador: ManagedAddress<M>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[derive(TopEncode, TypeAbi, Clone, Debug)]
+pub struct DepositorInfo<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub net_deposited_amount: BigUint<M>,
+    pub max_allocation: BigUint<M>,
+    pub deposits: ManagedVec<M, DepositorCurrencyInfo<M>>,
+    pub ambassadors: ManagedVec<M, AmbassadorFeeInfo<M>>,
+}
+
+#[derive(TopEncode, NestedEncode, ManagedVecItem, TypeAbi, Clone, Debug)]
+pub struct ReleasePayout<M: ManagedTypeApi> {
+    pub recipient: ManagedAddress<M>,
+    pub payments: ManagedVec<M, TokenPayment<M>>,
+}
+
+#[derive(TopEncode, TypeAbi, Clone, Debug)]
+pub struct ReleaseSimulation<M: ManagedTypeApi> {
+    pub release_state: ReleaseState,
+    pub overcommit_refunds: ManagedVec<M, ReleasePayout<M>>,
+    pub allocation_refunds: ManagedVec<M, ReleasePayout<M>>,
+    pub platform_fees: ReleasePayout<M>,
+    pub group_fees: ReleasePayout<M>,
+    pub ambassador_fees: ManagedVec<M, ReleasePayout<M>>,
+    pub retrieve: ReleasePayout<M>,
+    pub estimated_calls: usize,
+}
+
+// In memory copy of the bookkeeping of a deposit, used to simulate the release.
+#[derive(ManagedVecItem, Clone, Debug)]
+pub struct SimulatedDeposit<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub token_identifier: EgldOrEsdtTokenIdentifier<M>,
+    pub deposited_amount: BigUint<M>,
+    pub platform_fee: BigUint<M>,
+    pub group_fee: BigUint<M>,
+    pub ambassador_fee: BigUint<M>,
+    pub referral_fees: ManagedVec<M, AmbassadorFeeInfo<M>>,
+}
+
+#[derive(
+    TopEncode,
+    TopDecode,
+    NestedEncode,
+    NestedDecode,
+    ManagedVecItem,
+    PartialEq,
+    TypeAbi,
+    Clone,
+    Copy,
+    Debug,
+)]
+pub enum DepositFailure {
+    PoolNotOpenYet,
+    PoolClosed,
+    InvalidToken,
+    ZeroAmount,
+    ZeroPlatformFee,
+    ZeroGroupFee,
+    TooManyAmbassadors,
+    WalletNotRegistered,
+    FeesExceedAmount,
+    HardCapExceeded,
+    MaxDepositExceeded,
+    AmountTooLow,
+    InvalidIncrement,
+    NoActiveRound,
+    NotEligibleForRound,
+    AmountTooLowForRound,
+    RoundAllocationExceeded,
+    RoundCapExceeded,
+    PoolDisabled,
+}
+
+#[derive(TopEncode, TypeAbi, Clone, Debug)]
+pub struct DepositValidation<M: ManagedTypeApi> {
+    pub failures: ManagedVec<M, DepositFailure>,
+    pub max_amount: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait StorageModule {
     #[view(getPoolId)]
@@ -63,11 +301,11 @@ pub trait StorageModule {
 
     #[view(getPaymentCurrencies)]
     #[storage_mapper("payment_currencies")]
-    fn payment_currencies(&self) -> UnorderedSetMapper<TokenIdentifier>;
+    fn payment_currencies(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
 
     #[view(getCurrencyDecimals)]
     #[storage_mapper("currency_decimals")]
-    fn currency_decimals(&self, currency: &TokenIdentifier) -> SingleValueMapper<u32>;
+    fn currency_decimals(&self, currency: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u32>;
 
     #[view(getWallatDatabaseAddress)]
     #[storage_mapper("wallet_database_address")]
@@ -77,6 +315,34 @@ pub trait StorageModule {
     #[storage_mapper("signer")]
     fn signer(&self) -> SingleValueMapper<ManagedAddress>;
 
+    #[view(getFactoryAddress)]
+    #[storage_mapper("factory_address")]
+    fn factory_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getUseFactoryConfig)]
+    #[storage_mapper("use_factory_config")]
+    fn use_factory_config(&self) -> SingleValueMapper<bool>;
+
+    #[view(getPreviousSigner)]
+    #[storage_mapper("previous_signer")]
+    fn previous_signer(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPreviousSignerExpiry)]
+    #[storage_mapper("previous_signer_expiry")]
+    fn previous_signer_expiry(&self) -> SingleValueMapper<u64>;
+
+    #[view(getSigners)]
+    #[storage_mapper("signers")]
+    fn signers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getSignerThreshold)]
+    #[storage_mapper("signer_threshold")]
+    fn signer_threshold(&self) -> SingleValueMapper<usize>;
+
+    #[view(getConfigHash)]
+    #[storage_mapper("config_hash")]
+    fn config_hash(&self) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
     //
 
     #[view(getAddresses)]
@@ -89,36 +355,53 @@ pub trait StorageModule {
 
     #[view(getDepositedCurrencies)]
     #[storage_mapper("deposited_currencies")]
-    fn deposited_currencies(&self, address: &ManagedAddress)
-        -> UnorderedSetMapper<TokenIdentifier>;
+    fn deposited_currencies(
+        &self,
+        address: &ManagedAddress,
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
 
     #[view(getDepositedAmount)]
     #[storage_mapper("deposited_amount")]
     fn deposited_amount(
         &self,
         address: &ManagedAddress,
-        currency: &TokenIdentifier,
+        currency: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
+    #[view(getDeposit)]
+    #[storage_mapper("deposits")]
+    fn deposits(&self, deposit_id: &ManagedBuffer) -> SingleValueMapper<Deposit<Self::Api>>;
+
+    #[view(getDepositTranches)]
+    #[storage_mapper("deposit_tranches")]
+    fn deposit_tranches(
+        &self,
+        address: &ManagedAddress,
+        currency: &EgldOrEsdtTokenIdentifier,
+    ) -> QueueMapper<DepositTranche<Self::Api>>;
+
     #[view(getTotalAmount)]
     #[storage_mapper("total_amount")]
     fn total_amount(&self) -> SingleValueMapper<BigUint>;
 
     #[view(getTotalAmountCurrency)]
     #[storage_mapper("total_amount_currency")]
-    fn total_amount_currency(&self, currency: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn total_amount_currency(
+        &self,
+        currency: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
 
     #[view(getAddressPlatformFee)]
     #[storage_mapper("address_platform_fee")]
     fn address_platform_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
     #[view(getPlatformFee)]
     #[storage_mapper("platform_fee")]
-    fn platform_fee(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn platform_fee(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[view(getTotalPlatformFee)]
     #[storage_mapper("total_platform_fee")]
@@ -129,12 +412,12 @@ pub trait StorageModule {
     fn address_group_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
     #[view(getGroupFee)]
     #[storage_mapper("group_fee")]
-    fn group_fee(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn group_fee(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[view(getTotalGroupFee)]
     #[storage_mapper("total_group_fee")]
@@ -153,12 +436,12 @@ pub trait StorageModule {
     fn address_ambassador_fee(
         &self,
         address: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
     #[view(getAmbassadorFee)]
     #[storage_mapper("ambassador_fee")]
-    fn ambassador_fee(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+    fn ambassador_fee(&self, token: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
 
     #[view(getTotalAmbassadorFee)]
     #[storage_mapper("total_ambassador_fee")]
@@ -169,14 +452,14 @@ pub trait StorageModule {
     fn ambassador_currencies(
         &self,
         ambassador: &ManagedAddress,
-    ) -> UnorderedSetMapper<TokenIdentifier>;
+    ) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
 
     #[view(getReferralAmbassadorFee)]
     #[storage_mapper("referral_ambassador_fee")]
     fn referral_ambassador_fee(
         &self,
         ambassador: &ManagedAddress,
-        token: &TokenIdentifier,
+        token: &EgldOrEsdtTokenIdentifier,
     ) -> SingleValueMapper<BigUint>;
 
     #[view(getAddressToAmbassador)]
@@ -186,14 +469,110 @@ pub trait StorageModule {
         address: &ManagedAddress,
     ) -> UnorderedSetMapper<ManagedAddress>;
 
+    #[view(getAddressReferralAmbassadorFee)]
+    #[storage_mapper("address_referral_ambassador_fee")]
+    fn address_referral_ambassador_fee(
+        &self,
+        address: &ManagedAddress,
+        ambassador: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getOversubscriptionEnabled)]
+    #[storage_mapper("oversubscription_enabled")]
+    fn oversubscription_enabled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getDepositPolicy)]
+    #[storage_mapper("deposit_policy")]
+    fn deposit_policy(&self) -> SingleValueMapper<DepositPolicy>;
+
+    #[view(getWalletMaxAllocation)]
+    #[storage_mapper("wallet_max_allocation")]
+    fn wallet_max_allocation(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getWithdrawalPenaltySchedule)]
+    #[storage_mapper("withdrawal_penalty_schedule")]
+    fn withdrawal_penalty_schedule(&self) -> VecMapper<PenaltyTier>;
+
+    #[view(getRounds)]
+    #[storage_mapper("rounds")]
+    fn rounds(&self) -> VecMapper<Round<Self::Api>>;
+
+    #[view(getRoundWhitelist)]
+    #[storage_mapper("round_whitelist")]
+    fn round_whitelist(&self, round_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getRoundTotal)]
+    #[storage_mapper("round_total")]
+    fn round_total(&self, round_id: usize) -> SingleValueMapper<BigUint>;
+
+    #[view(getRoundDepositedAmount)]
+    #[storage_mapper("round_deposited_amount")]
+    fn round_deposited_amount(
+        &self,
+        round_id: usize,
+        address: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(getAllocationTotal)]
+    #[storage_mapper("allocation_total")]
+    fn allocation_total(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getAllocationIndex)]
+    #[storage_mapper("allocation_index")]
+    fn allocation_index(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("allocations")]
+    fn allocations(&self, address: &ManagedAddress) -> SingleValueMapper<Allocation<Self::Api>>;
+
+    #[view(getReleasePlan)]
+    #[storage_mapper("release_plan")]
+    fn release_plan(&self) -> VecMapper<OvercommitEntry<Self::Api>>;
+
+    #[view(getReleasePlanHash)]
+    #[storage_mapper("release_plan_hash")]
+    fn release_plan_hash(&self) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[view(getReleasePlanFinalized)]
+    #[storage_mapper("release_plan_finalized")]
+    fn release_plan_finalized(&self) -> SingleValueMapper<bool>;
+
     #[view(getOvercommitedIndex)]
     #[storage_mapper("overcommited_index")]
     fn overcommited_index(&self) -> SingleValueMapper<usize>;
 
+    #[view(getPoolCancelled)]
+    #[storage_mapper("pool_cancelled")]
+    fn pool_cancelled(&self) -> SingleValueMapper<bool>;
+
+    #[view(getLastPoolStatus)]
+    #[storage_mapper("last_pool_status")]
+    fn last_pool_status(&self) -> SingleValueMapper<PoolStatus>;
+
+    #[view(getRefundStarted)]
+    #[storage_mapper("refund_started")]
+    fn refund_started(&self) -> SingleValueMapper<bool>;
+
+    #[view(getReleaseStarted)]
+    #[storage_mapper("release_started")]
+    fn release_started(&self) -> SingleValueMapper<bool>;
+
     #[view(getReleaseState)]
     #[storage_mapper("release_state")]
     fn release_state(&self) -> SingleValueMapper<ReleaseState>;
 
+    #[view(getFeeClaimMode)]
+    #[storage_mapper("fee_claim_mode")]
+    fn fee_claim_mode(&self) -> SingleValueMapper<bool>;
+
+    #[view(getFeeClaimDeadline)]
+    #[storage_mapper("fee_claim_deadline")]
+    fn fee_claim_deadline(&self) -> SingleValueMapper<u64>;
+
+    #[view(getFeesSwept)]
+    #[storage_mapper("fees_swept")]
+    fn fees_swept(&self) -> SingleValueMapper<bool>;
+
     #[view(raisePoolEnabled)]
     #[storage_mapper("raise_pool_enabled")]
     fn raise_pool_enabled(&self) -> SingleValueMapper<bool>;
@@ -201,4 +580,10 @@ pub trait StorageModule {
     #[view(getOwner)]
     #[storage_mapper("owner")]
     fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("used_signatures")]
+    fn used_signatures(&self, bucket: u64) -> UnorderedSetMapper<ManagedByteArray<Self::Api, 32>>;
+
+    #[storage_mapper("used_signature_buckets")]
+    fn used_signature_buckets(&self) -> UnorderedSetMapper<u64>;
 }
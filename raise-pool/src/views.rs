@@ -0,0 +1,837 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::helper::DEFAULT_DECIMALS;
+use crate::storage::{
+    AmbassadorFeeInfo, CurrencyInfo, DepositFailure, DepositPolicy, DepositValidation,
+    DepositorCurrencyInfo, DepositorInfo, OvercommitEntry, PoolInfo, PoolStatus, ReleasePayout,
+    ReleaseSimulation, ReleaseState, RoundEligibility, SimulatedDeposit, TokenPayment,
+};
+use crate::MAX_TX_PER_RELEASE;
+
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    crate::storage::StorageModule
+    + crate::helper::HelperModule
+    + crate::signers::SignersModule
+    + crate::config::ConfigModule
+    + crate::events::EventsModule
+    + crate::status::StatusModule
+{
+    #[view(getPoolInfo)]
+    fn get_pool_info(&self) -> PoolInfo<Self::Api> {
+        PoolInfo {
+            pool_id: self.pool_id().get(),
+            owner: self.owner().get(),
+            status: self.pool_status(),
+            release_state: self.release_state().get(),
+            enabled: self.raise_pool_enabled().get(),
+            soft_cap: self.soft_cap().get(),
+            hard_cap: self.hard_cap().get(),
+            min_deposit: self.min_deposit().get(),
+            max_deposit: self.max_deposit().get(),
+            deposit_increments: self.deposit_increments().get(),
+            deposit_policy: self.deposit_policy().get(),
+            start_date: self.start_date().get(),
+            end_date: self.end_date().get(),
+            refund_enabled: self.refund_enabled().get(),
+            refund_deadline: self.refund_deadline().get(),
+            oversubscription_enabled: self.oversubscription_enabled().get(),
+            fee_claim_mode: self.fee_claim_mode().get(),
+            platform_fee_wallet: self.configured_platform_fee_wallet(),
+            group_fee_wallet: self.group_fee_wallet().get(),
+            total_amount: self.total_amount().get(),
+            total_platform_fee: self.total_platform_fee().get(),
+            total_group_fee: self.total_group_fee().get(),
+            total_ambassador_fee: self.total_ambassador_fee().get(),
+            depositors_count: self.addresses().len(),
+            ambassadors_count: self.ambassadors().len(),
+            rounds_count: self.rounds().len(),
+            currencies: self.currencies_info(),
+        }
+    }
+
+    #[view(getDepositorInfo)]
+    fn get_depositor_info(&self, address: ManagedAddress) -> DepositorInfo<Self::Api> {
+        let mut deposits = ManagedVec::new();
+        for token in self.deposited_currencies(&address).iter() {
+            deposits.push(DepositorCurrencyInfo {
+                deposited_amount: self.deposited_amount(&address, &token).get(),
+                platform_fee: self.address_platform_fee(&address, &token).get(),
+                group_fee: self.address_group_fee(&address, &token).get(),
+                ambassador_fee: self.address_ambassador_fee(&address, &token).get(),
+                token_identifier: token,
+            });
+        }
+
+        let mut ambassadors = ManagedVec::new();
+        for ambassador in self.address_to_ambassadors(&address).iter() {
+            for token in self.deposited_currencies(&address).iter() {
+                let amount = self
+                    .address_referral_ambassador_fee(&address, &ambassador, &token)
+                    .get();
+                if amount == 0 {
+                    continue;
+                }
+                ambassadors.push(AmbassadorFeeInfo {
+                    ambassador: ambassador.clone(),
+                    token_identifier: token,
+                    amount,
+                });
+            }
+        }
+
+        DepositorInfo {
+            net_deposited_amount: self.net_deposited_amount(&address),
+            max_allocation: self.max_deposit_denominated(&address),
+            address,
+            deposits,
+            ambassadors,
+        }
+    }
+
+    #[view(getAddressesCount)]
+    fn get_addresses_count(&self) -> usize {
+        self.addresses().len()
+    }
+
+    #[view(getAmbassadorsCount)]
+    fn get_ambassadors_count(&self) -> usize {
+        self.ambassadors().len()
+    }
+
+    // Pages are read by position in the set, starting at 0. Removing an entry moves the
+    // last one in its place, so pages should be read while the set is not changing.
+    #[view(getAddressesPage)]
+    fn get_addresses_page(
+        &self,
+        start: usize,
+        count: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedVec<TokenPayment<Self::Api>>>> {
+        let mut result = MultiValueEncoded::new();
+        let addresses = self.addresses();
+        let end = core::cmp::min(start.saturating_add(count), addresses.len());
+        for index in start..end {
+            let address = addresses.get_by_index(index + 1);
+            let mut payments = ManagedVec::new();
+            for token in self.deposited_currencies(&address).iter() {
+                let amount = self.deposited_amount(&address, &token).get();
+                payments.push(TokenPayment::new(token, amount));
+            }
+            result.push(MultiValue2((address, payments)));
+        }
+        result
+    }
+
+    #[view(getAmbassadorsPage)]
+    fn get_ambassadors_page(
+        &self,
+        start: usize,
+        count: usize,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedVec<TokenPayment<Self::Api>>>> {
+        let mut result = MultiValueEncoded::new();
+        let ambassadors = self.ambassadors();
+        let end = core::cmp::min(start.saturating_add(count), ambassadors.len());
+        for index in start..end {
+            let ambassador = ambassadors.get_by_index(index + 1);
+            let mut fees = ManagedVec::new();
+            for token in self.ambassador_currencies(&ambassador).iter() {
+                let amount = self.referral_ambassador_fee(&ambassador, &token).get();
+                fees.push(TokenPayment::new(token, amount));
+            }
+            result.push(MultiValue2((ambassador, fees)));
+        }
+        result
+    }
+
+    #[view(getClaimableFees)]
+    fn get_claimable_fees(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        if self.fees_swept().get() {
+            return result;
+        }
+        for token in self.payment_currencies().iter() {
+            let amount = self.claimable_fee(&address, &token);
+            if amount > 0 {
+                result.push(MultiValue2((token, amount)));
+            }
+        }
+        result
+    }
+
+    #[view(getWithdrawalPenalty)]
+    fn get_withdrawal_penalty(
+        &self,
+        address: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) -> BigUint {
+        self.withdrawal_penalty(&address, &token, &amount)
+    }
+
+    #[view(getActiveRound)]
+    fn get_active_round(&self) -> usize {
+        self.active_round()
+    }
+
+    #[view(getWalletAllocation)]
+    fn get_wallet_allocation(&self, address: ManagedAddress) -> MultiValue2<BigUint, BigUint> {
+        (
+            self.max_deposit_denominated(&address),
+            self.net_deposited_amount(&address),
+        )
+            .into()
+    }
+
+    #[view(getRemainingAllocation)]
+    fn get_remaining_allocation(&self, address: ManagedAddress) -> BigUint {
+        let max_deposit = self.max_deposit_denominated(&address);
+        let deposited = self.net_deposited_amount(&address);
+        if deposited >= max_deposit {
+            return BigUint::zero();
+        }
+        max_deposit - deposited
+    }
+
+    #[view(getAllocation)]
+    fn get_allocation(&self, address: ManagedAddress) -> BigUint {
+        if !self.allocations(&address).is_empty() {
+            return self.allocations(&address).get().allocation;
+        }
+        let net_deposited_amount = self.net_deposited_amount(&address);
+        match self.allocation_ratio() {
+            Some((hard_cap, allocation_total)) => {
+                net_deposited_amount * hard_cap / allocation_total
+            }
+            None => net_deposited_amount,
+        }
+    }
+
+    #[view(getExcessRefund)]
+    fn get_excess_refund(
+        &self,
+        address: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        if !self.allocations(&address).is_empty() {
+            for payment in self.allocations(&address).get().excess_refund.iter() {
+                result.push(MultiValue2((
+                    payment.token_identifier.clone(),
+                    payment.amount.clone(),
+                )));
+            }
+            return result;
+        }
+        if let Some((hard_cap, allocation_total)) = self.allocation_ratio() {
+            for token in self.deposited_currencies(&address).iter() {
+                let deposited = self.deposited_amount(&address, &token).get();
+                let kept = &deposited * &hard_cap / &allocation_total;
+                if deposited > kept {
+                    result.push(MultiValue2((token, deposited - kept)));
+                }
+            }
+        }
+        result
+    }
+
+    // Runs the remaining release stages in memory, applying the same fee reversals as the
+    // release itself. The given overcommit list replaces the remaining entries of the stored
+    // release plan, so a plan can be checked before it is submitted. Gas is not simulated,
+    // so a release can take more calls than estimated.
+    #[view(simulateRelease)]
+    fn simulate_release(
+        &self,
+        overcommited: MultiValueEncoded<
+            MultiValue3<ManagedAddress, EgldOrEsdtTokenIdentifier, BigUint>,
+        >,
+    ) -> ReleaseSimulation<Self::Api> {
+        let release_state = self.release_state().get();
+        let fee_claim_mode = self.fee_claim_mode().get();
+        let mut currencies = self.currencies_info();
+        let mut deposits = ManagedVec::new();
+        let mut ambassador_cuts = ManagedVec::new();
+        let mut overcommit_refunds = ManagedVec::new();
+        let mut allocation_refunds = ManagedVec::new();
+        let mut estimated_calls = 1;
+
+        if release_state == ReleaseState::None {
+            let mut pending_entries = ManagedVec::<Self::Api, OvercommitEntry<Self::Api>>::new();
+            if overcommited.is_empty() {
+                let release_plan = self.release_plan();
+                for index in self.overcommited_index().get()..release_plan.len() {
+                    pending_entries.push(release_plan.get(index + 1));
+                }
+            } else {
+                for overcommit in overcommited {
+                    let (address, token, amount) = overcommit.into_tuple();
+                    pending_entries.push(OvercommitEntry {
+                        address,
+                        token,
+                        amount,
+                    });
+                }
+            }
+            estimated_calls += self.extra_release_calls(pending_entries.len());
+            for entry in pending_entries.iter() {
+                let deposit_index =
+                    self.simulated_deposit_index(&mut deposits, &entry.address, &entry.token);
+                let refund = self.simulate_reduce_deposit(
+                    &mut deposits,
+                    deposit_index,
+                    &entry.amount,
+                    &mut currencies,
+                    &mut ambassador_cuts,
+                );
+                if refund > 0 {
+                    let mut payments = ManagedVec::new();
+                    payments.push(TokenPayment::new(entry.token, refund));
+                    push_payout(&mut overcommit_refunds, entry.address, payments);
+                }
+            }
+
+            if self.oversubscription_enabled().get() {
+                let hard_cap = self.hard_cap().get() * 10_u64.pow(DEFAULT_DECIMALS);
+                let allocation_total = if self.allocation_total().is_empty() {
+                    self.simulated_net_total(&currencies)
+                } else {
+                    self.allocation_total().get()
+                };
+                if allocation_total > hard_cap {
+                    let mut remaining_addresses = 0;
+                    for address in self.addresses().iter().skip(self.allocation_index().get()) {
+                        let mut payments = ManagedVec::new();
+                        let mut has_deposits = false;
+                        for token in self.deposited_currencies(&address).iter() {
+                            let deposit_index =
+                                self.simulated_deposit_index(&mut deposits, &address, &token);
+                            let deposited = deposits.get(deposit_index).deposited_amount;
+                            if deposited == 0 {
+                                continue;
+                            }
+                            has_deposits = true;
+                            let kept = &deposited * &hard_cap / &allocation_total;
+                            let refund = self.simulate_reduce_deposit(
+                                &mut deposits,
+                                deposit_index,
+                                &(deposited - kept),
+                                &mut currencies,
+                                &mut ambassador_cuts,
+                            );
+                            if refund > 0 {
+                                payments.push(TokenPayment::new(token, refund));
+                            }
+                        }
+                        if has_deposits {
+                            remaining_addresses += 1;
+                        }
+                        push_payout(&mut allocation_refunds, address, payments);
+                    }
+                    estimated_calls += self.extra_release_calls(remaining_addresses);
+                }
+            }
+        }
+
+        let platform_pending = matches!(
+            release_state,
+            ReleaseState::None | ReleaseState::OvercommitersReleased
+        );
+        let mut platform_payments = ManagedVec::new();
+        let mut group_payments = ManagedVec::new();
+        for index in 0..currencies.len() {
+            if fee_claim_mode {
+                break;
+            }
+            let mut currency = currencies.get_mut(index);
+            let token = currency.token_identifier.clone();
+            let platform_fee = currency.platform_fee.clone();
+            let group_fee = currency.group_fee.clone();
+            if platform_pending && platform_fee > 0 {
+                currency.total_amount -= &platform_fee;
+                platform_payments.push(TokenPayment::new(token.clone(), platform_fee));
+            }
+            if (platform_pending || release_state == ReleaseState::PlatformReleased)
+                && group_fee > 0
+            {
+                currency.total_amount -= &group_fee;
+                group_payments.push(TokenPayment::new(token, group_fee));
+            }
+        }
+
+        let mut ambassador_fees = ManagedVec::new();
+        let ambassadors_pending = matches!(
+            release_state,
+            ReleaseState::None
+                | ReleaseState::OvercommitersReleased
+                | ReleaseState::PlatformReleased
+                | ReleaseState::GroupReleased
+        );
+        if !fee_claim_mode && ambassadors_pending {
+            let mut remaining_ambassadors = 0;
+            for ambassador in self
+                .ambassadors()
+                .iter()
+                .skip(self.release_ambassador_index().get())
+            {
+                let mut payments = ManagedVec::new();
+                for token in self.ambassador_currencies(&ambassador).iter() {
+                    let mut amount = self.referral_ambassador_fee(&ambassador, &token).get();
+                    for cut in ambassador_cuts.iter() {
+                        if cut.ambassador == ambassador && cut.token_identifier == token {
+                            amount = if amount > cut.amount {
+                                amount - cut.amount
+                            } else {
+                                BigUint::zero()
+                            };
+                        }
+                    }
+                    if amount == 0 {
+                        continue;
+                    }
+                    if let Some(currency_index) = find_currency(&currencies, &token) {
+                        currencies.get_mut(currency_index).total_amount -= &amount;
+                    }
+                    payments.push(TokenPayment::new(token, amount));
+                }
+                if !payments.is_empty() {
+                    remaining_ambassadors += 1;
+                }
+                push_payout(&mut ambassador_fees, ambassador, payments);
+            }
+            estimated_calls += self.extra_release_calls(remaining_ambassadors);
+        }
+
+        let mut retrieve_payments = ManagedVec::new();
+        if release_state != ReleaseState::Retrieved {
+            for currency in currencies.iter() {
+                let mut amount = currency.total_amount;
+                if fee_claim_mode {
+                    amount -= currency.platform_fee + currency.group_fee + currency.ambassador_fee;
+                }
+                if amount > 0 {
+                    retrieve_payments.push(TokenPayment::new(currency.token_identifier, amount));
+                }
+            }
+        } else {
+            estimated_calls = 0;
+        }
+
+        ReleaseSimulation {
+            release_state,
+            overcommit_refunds,
+            allocation_refunds,
+            platform_fees: ReleasePayout {
+                recipient: self.configured_platform_fee_wallet(),
+                payments: platform_payments,
+            },
+            group_fees: ReleasePayout {
+                recipient: self.group_fee_wallet().get(),
+                payments: group_payments,
+            },
+            ambassador_fees,
+            retrieve: ReleasePayout {
+                recipient: self.owner().get(),
+                payments: retrieve_payments,
+            },
+            estimated_calls,
+        }
+    }
+
+    // Runs the checks of deposit for a single payment without the signature, timestamp and
+    // deposit_id checks, which depend on the backend. max_amount is the largest payment,
+    // fees included, that the pool currently accepts from the wallet with the given fees.
+    #[view(validateDeposit)]
+    fn validate_deposit_view(
+        &self,
+        address: ManagedAddress,
+        token: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        platform_fee: BigUint,
+        group_fee: BigUint,
+        ambassadors: MultiValueEncoded<MultiValue2<BigUint, ManagedAddress>>,
+    ) -> DepositValidation<Self::Api> {
+        let mut failures = ManagedVec::new();
+        match self.pool_status() {
+            PoolStatus::Open => {}
+            PoolStatus::Scheduled => failures.push(DepositFailure::PoolNotOpenYet),
+            PoolStatus::Disabled => failures.push(DepositFailure::PoolDisabled),
+            _ => failures.push(DepositFailure::PoolClosed),
+        }
+        let valid_token = self.payment_currencies().contains(&token);
+        if !valid_token {
+            failures.push(DepositFailure::InvalidToken);
+        }
+        if amount == 0 {
+            failures.push(DepositFailure::ZeroAmount);
+        }
+        if platform_fee == 0 {
+            failures.push(DepositFailure::ZeroPlatformFee);
+        }
+        if group_fee == 0 {
+            failures.push(DepositFailure::ZeroGroupFee);
+        }
+        if ambassadors.len() > 2 {
+            failures.push(DepositFailure::TooManyAmbassadors);
+        }
+        if !self.is_registered(&address) {
+            failures.push(DepositFailure::WalletNotRegistered);
+        }
+
+        let unit = BigUint::from(10_u64.pow(DEFAULT_DECIMALS));
+        let round_id = self.active_round();
+        if !self.rounds().is_empty() {
+            if round_id == 0 {
+                failures.push(DepositFailure::NoActiveRound);
+            } else if self.rounds().get(round_id).eligibility == RoundEligibility::Whitelist
+                && !self.round_whitelist(round_id).contains(&address)
+            {
+                failures.push(DepositFailure::NotEligibleForRound);
+            }
+        }
+        if !failures.is_empty() && !self.only_amount_failures(&failures) {
+            return DepositValidation {
+                failures,
+                max_amount: BigUint::zero(),
+            };
+        }
+
+        let mut total_fees = platform_fee + group_fee;
+        for ambassador in ambassadors {
+            let (ambassador_fee, _) = ambassador.into_tuple();
+            total_fees += ambassador_fee;
+        }
+        let net_deposited = self.net_deposited_amount(&address);
+        if amount > total_fees {
+            let net_amount = self.denominate_payment(&token, &(&amount - &total_fees));
+            if !self.oversubscription_enabled().get()
+                && self.net_total_amount() + &net_amount > self.hard_cap().get() * &unit
+            {
+                failures.push(DepositFailure::HardCapExceeded);
+            }
+            if &net_deposited + &net_amount > self.max_deposit_denominated(&address) {
+                failures.push(DepositFailure::MaxDepositExceeded);
+            }
+            match self.deposit_policy().get() {
+                DepositPolicy::Off => {}
+                DepositPolicy::PerDeposit => {
+                    self.check_deposit_amount(&mut failures, &net_amount);
+                }
+                DepositPolicy::CumulativePerWallet => {
+                    self.check_deposit_amount(&mut failures, &(&net_deposited + &net_amount));
+                }
+            }
+            if round_id > 0 {
+                let round = self.rounds().get(round_id);
+                let deposited = self.round_deposited_amount(round_id, &address).get() + &net_amount;
+                if deposited < round.min_deposit * &unit {
+                    failures.push(DepositFailure::AmountTooLowForRound);
+                }
+                if deposited > round.max_deposit * &unit {
+                    failures.push(DepositFailure::RoundAllocationExceeded);
+                }
+                if self.round_total(round_id).get() + &net_amount > round.cap * &unit {
+                    failures.push(DepositFailure::RoundCapExceeded);
+                }
+            }
+        } else if amount > 0 {
+            failures.push(DepositFailure::FeesExceedAmount);
+        }
+
+        let max_net_deposit = self.max_net_deposit(&address, &net_deposited, round_id);
+        let scale = 10_u64.pow(DEFAULT_DECIMALS - self.currency_decimals(&token).get());
+        let max_net_amount = max_net_deposit / scale;
+        let max_amount = if max_net_amount > 0 {
+            max_net_amount + total_fees
+        } else {
+            BigUint::zero()
+        };
+        DepositValidation {
+            failures,
+            max_amount,
+        }
+    }
+
+    fn only_amount_failures(&self, failures: &ManagedVec<DepositFailure>) -> bool {
+        failures.iter().all(|failure| {
+            matches!(
+                failure,
+                DepositFailure::ZeroAmount
+                    | DepositFailure::ZeroPlatformFee
+                    | DepositFailure::ZeroGroupFee
+                    | DepositFailure::TooManyAmbassadors
+            )
+        })
+    }
+
+    fn check_deposit_amount(&self, failures: &mut ManagedVec<DepositFailure>, amount: &BigUint) {
+        let unit = BigUint::from(10_u64.pow(DEFAULT_DECIMALS));
+        if *amount < self.min_deposit().get() * &unit {
+            failures.push(DepositFailure::AmountTooLow);
+        }
+        let increment = self.deposit_increments().get() * &unit;
+        if increment > 0 && amount % &increment != 0 {
+            failures.push(DepositFailure::InvalidIncrement);
+        }
+    }
+
+    // Largest net amount (denominated) the wallet can still deposit, bounded by its
+    // allocation, the hard cap, the active round and the deposit increments. It is 0 when
+    // even that amount stays below the deposit minimum or the round minimum.
+    fn max_net_deposit(
+        &self,
+        address: &ManagedAddress,
+        net_deposited: &BigUint,
+        round_id: usize,
+    ) -> BigUint {
+        let unit = BigUint::from(10_u64.pow(DEFAULT_DECIMALS));
+        let mut max_amount = remaining(&self.max_deposit_denominated(address), net_deposited);
+        if !self.oversubscription_enabled().get() {
+            let hard_cap_room =
+                remaining(&(self.hard_cap().get() * &unit), &self.net_total_amount());
+            max_amount = max_amount.min(hard_cap_room);
+        }
+        let mut round_minimum = None;
+        if round_id > 0 {
+            let round = self.rounds().get(round_id);
+            let round_deposited = self.round_deposited_amount(round_id, address).get();
+            let round_room = remaining(&(round.max_deposit * &unit), &round_deposited);
+            let round_cap_room = remaining(&(round.cap * &unit), &self.round_total(round_id).get());
+            max_amount = max_amount.min(round_room).min(round_cap_room);
+            round_minimum = Some((round_deposited, round.min_deposit * &unit));
+        }
+
+        let min_deposit = self.min_deposit().get() * &unit;
+        let increment = self.deposit_increments().get() * &unit;
+        let max_amount = match self.deposit_policy().get() {
+            DepositPolicy::Off => max_amount,
+            DepositPolicy::PerDeposit => {
+                let max_amount = if increment == 0 {
+                    max_amount
+                } else {
+                    &max_amount - &(&max_amount % &increment)
+                };
+                if max_amount < min_deposit {
+                    BigUint::zero()
+                } else {
+                    max_amount
+                }
+            }
+            DepositPolicy::CumulativePerWallet => {
+                let mut total = net_deposited + &max_amount;
+                if increment > 0 {
+                    total -= &(&total % &increment);
+                }
+                if total < min_deposit {
+                    BigUint::zero()
+                } else {
+                    remaining(&total, net_deposited)
+                }
+            }
+        };
+        match round_minimum {
+            Some((round_deposited, minimum)) if &round_deposited + &max_amount < minimum => {
+                BigUint::zero()
+            }
+            _ => max_amount,
+        }
+    }
+
+    fn allocation_ratio(&self) -> Option<(BigUint, BigUint)> {
+        if !self.oversubscription_enabled().get() {
+            return None;
+        }
+        let hard_cap = self.hard_cap().get() * 10_u64.pow(DEFAULT_DECIMALS);
+        let allocation_total = if self.allocation_total().is_empty() {
+            self.net_total_amount()
+        } else {
+            self.allocation_total().get()
+        };
+        if allocation_total <= hard_cap {
+            return None;
+        }
+        Some((hard_cap, allocation_total))
+    }
+
+    fn currencies_info(&self) -> ManagedVec<CurrencyInfo<Self::Api>> {
+        let mut currencies = ManagedVec::new();
+        for token in self.payment_currencies().iter() {
+            currencies.push(CurrencyInfo {
+                decimals: self.currency_decimals(&token).get(),
+                total_amount: self.total_amount_currency(&token).get(),
+                platform_fee: self.platform_fee(&token).get(),
+                group_fee: self.group_fee(&token).get(),
+                ambassador_fee: self.ambassador_fee(&token).get(),
+                token_identifier: token,
+            });
+        }
+        currencies
+    }
+
+    // Every batched stage of the release stops after MAX_TX_PER_RELEASE entries and the
+    // next call resumes it, while the following stages continue in the same call.
+    fn extra_release_calls(&self, entries: usize) -> usize {
+        entries.saturating_sub(1) / MAX_TX_PER_RELEASE as usize
+    }
+
+    fn simulated_net_total(&self, currencies: &ManagedVec<CurrencyInfo<Self::Api>>) -> BigUint {
+        let mut net_total = BigUint::zero();
+        for currency in currencies.iter() {
+            let amount = currency.total_amount
+                - currency.platform_fee
+                - currency.group_fee
+                - currency.ambassador_fee;
+            net_total += self.denominate_payment(&currency.token_identifier, &amount);
+        }
+        net_total
+    }
+
+    fn simulated_deposit_index(
+        &self,
+        deposits: &mut ManagedVec<SimulatedDeposit<Self::Api>>,
+        address: &ManagedAddress,
+        token: &EgldOrEsdtTokenIdentifier,
+    ) -> usize {
+        for (index, deposit) in deposits.iter().enumerate() {
+            if deposit.address == *address && deposit.token_identifier == *token {
+                return index;
+            }
+        }
+
+        let mut referral_fees = ManagedVec::new();
+        for ambassador in self.address_to_ambassadors(address).iter() {
+            referral_fees.push(AmbassadorFeeInfo {
+                amount: self
+                    .address_referral_ambassador_fee(address, &ambassador, token)
+                    .get(),
+                ambassador,
+                token_identifier: token.clone(),
+            });
+        }
+        let deposited_amount = if self.deposited_currencies(address).contains(token) {
+            self.deposited_amount(address, token).get()
+        } else {
+            BigUint::zero()
+        };
+        deposits.push(SimulatedDeposit {
+            address: address.clone(),
+            token_identifier: token.clone(),
+            deposited_amount,
+            platform_fee: self.address_platform_fee(address, token).get(),
+            group_fee: self.address_group_fee(address, token).get(),
+            ambassador_fee: self.address_ambassador_fee(address, token).get(),
+            referral_fees,
+        });
+        deposits.len() - 1
+    }
+
+    // Mirrors reduce_deposit: fees are reversed proportionally to the refunded amount, so a
+    // full refund reverses all the fees of the deposit.
+    fn simulate_reduce_deposit(
+        &self,
+        deposits: &mut ManagedVec<SimulatedDeposit<Self::Api>>,
+        deposit_index: usize,
+        amount: &BigUint,
+        currencies: &mut ManagedVec<CurrencyInfo<Self::Api>>,
+        ambassador_cuts: &mut ManagedVec<AmbassadorFeeInfo<Self::Api>>,
+    ) -> BigUint {
+        let mut deposit = deposits.get_mut(deposit_index);
+        let deposited = deposit.deposited_amount.clone();
+        if deposited == 0 {
+            return BigUint::zero();
+        }
+        let full_refund = *amount >= deposited;
+        let amount = if full_refund {
+            deposited.clone()
+        } else {
+            amount.clone()
+        };
+
+        let platform_fee_cut = &deposit.platform_fee * &amount / &deposited;
+        let group_fee_cut = &deposit.group_fee * &amount / &deposited;
+        let mut ambassador_fee_cut = BigUint::zero();
+        let mut referral_fees = ManagedVec::new();
+        for referral_fee in deposit.referral_fees.iter() {
+            let referral_fee_cut = &referral_fee.amount * &amount / &deposited;
+            ambassador_fee_cut += &referral_fee_cut;
+            add_ambassador_cut(
+                ambassador_cuts,
+                &referral_fee.ambassador,
+                &deposit.token_identifier,
+                &referral_fee_cut,
+            );
+            referral_fees.push(AmbassadorFeeInfo {
+                amount: &referral_fee.amount - &referral_fee_cut,
+                ..referral_fee
+            });
+        }
+        if full_refund {
+            ambassador_fee_cut = deposit.ambassador_fee.clone();
+        }
+
+        if let Some(currency_index) = find_currency(currencies, &deposit.token_identifier) {
+            let mut currency = currencies.get_mut(currency_index);
+            currency.total_amount -= &amount;
+            currency.platform_fee -= &platform_fee_cut;
+            currency.group_fee -= &group_fee_cut;
+            currency.ambassador_fee -= &ambassador_fee_cut;
+        }
+        deposit.deposited_amount -= &amount;
+        deposit.platform_fee -= platform_fee_cut;
+        deposit.group_fee -= group_fee_cut;
+        deposit.ambassador_fee -= ambassador_fee_cut;
+        deposit.referral_fees = referral_fees;
+        amount
+    }
+}
+
+fn find_currency<M: ManagedTypeApi>(
+    currencies: &ManagedVec<M, CurrencyInfo<M>>,
+    token: &EgldOrEsdtTokenIdentifier<M>,
+) -> Option<usize> {
+    currencies
+        .iter()
+        .position(|currency| currency.token_identifier == *token)
+}
+
+fn add_ambassador_cut<M: ManagedTypeApi>(
+    ambassador_cuts: &mut ManagedVec<M, AmbassadorFeeInfo<M>>,
+    ambassador: &ManagedAddress<M>,
+    token: &EgldOrEsdtTokenIdentifier<M>,
+    amount: &BigUint<M>,
+) {
+    for index in 0..ambassador_cuts.len() {
+        let mut cut = ambassador_cuts.get_mut(index);
+        if cut.ambassador == *ambassador && cut.token_identifier == *token {
+            cut.amount += amount;
+            return;
+        }
+    }
+    ambassador_cuts.push(AmbassadorFeeInfo {
+        ambassador: ambassador.clone(),
+        token_identifier: token.clone(),
+        amount: amount.clone(),
+    });
+}
+
+fn push_payout<M: ManagedTypeApi>(
+    payouts: &mut ManagedVec<M, ReleasePayout<M>>,
+    recipient: ManagedAddress<M>,
+    payments: ManagedVec<M, TokenPayment<M>>,
+) {
+    if !payments.is_empty() {
+        payouts.push(ReleasePayout {
+            recipient,
+            payments,
+        });
+    }
+}
+
+fn remaining<M: ManagedTypeApi>(limit: &BigUint<M>, used: &BigUint<M>) -> BigUint<M> {
+    if limit > used {
+        limit - used
+    } else {
+        BigUint::zero()
+    }
+}
@@ -0,0 +1,1778 @@
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use multiversx_sc::{
+    codec::multi_types::MultiValue3,
+    types::{
+        Address, BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedByteArray, ManagedVec,
+        MultiValueEncoded,
+    },
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_buffer, managed_token_id_wrapped,
+    multiversx_chain_vm::tx_mock::TxResult, scenario_model::*, DebugApi, ScenarioWorld,
+    WhiteboxContract,
+};
+use raise_pool::{
+    status::StatusModule,
+    storage::{
+        DepositFailure, PoolStatus, ReleasePayout, ReleaseState, RoundEligibility, StorageModule,
+    },
+    views::ViewsModule,
+    RaisePool, FEE_CLAIM_PERIOD, REFUND_CLAIM_GRACE_PERIOD,
+};
+use wallet_database::WalletDatabase;
+
+const RAISE_POOL_PATH_EXPR: &str = "mxsc:output/raise-pool.mxsc.json";
+const WALLET_DATABASE_PATH_EXPR: &str = "mxsc:../wallet-database/output/wallet-database.mxsc.json";
+
+const OWNER: &str = "address:owner";
+const USER: &str = "address:user";
+const OTHER_USER: &str = "address:other-user";
+const PARTNER: &str = "address:partner";
+const AMBASSADOR: &str = "address:ambassador";
+const PLATFORM_FEE_WALLET: &str = "address:platform-fee-wallet";
+const GROUP_FEE_WALLET: &str = "address:group-fee-wallet";
+const RAISE_POOL: &str = "sc:raise-pool";
+const WALLET_DATABASE: &str = "sc:wallet-database";
+
+const POOL_ID: &[u8] = b"pool-1";
+const TOKEN: &str = "USDC-123456";
+const TOKEN_EXPR: &str = "str:USDC-123456";
+const TOKEN_DECIMALS: u32 = 6;
+const OTHER_TOKEN: &str = "USDT-123456";
+const OTHER_TOKEN_EXPR: &str = "str:USDT-123456";
+const UNIT: u64 = 1_000_000;
+const INITIAL_BALANCE: u64 = 1_000 * UNIT;
+const FEE: u64 = UNIT / 2;
+const EGLD: &str = "EGLD";
+const EGLD_UNIT: u64 = 1_000_000_000_000_000_000;
+const EGLD_FEE: u64 = EGLD_UNIT / 2;
+const EGLD_BALANCE: u64 = 9 * EGLD_UNIT;
+
+const DEPLOY_TIMESTAMP: u64 = 100;
+const START_DATE: u64 = 1_000;
+const END_DATE: u64 = 2_000;
+const REFUND_DEADLINE: u64 = 1_500;
+const SALE_TIMESTAMP: u64 = 1_100;
+
+type RaisePoolContract = WhiteboxContract<raise_pool::ContractObj<DebugApi>>;
+type OvercommitEntries = MultiValueEncoded<
+    DebugApi,
+    MultiValue3<ManagedAddress<DebugApi>, EgldOrEsdtTokenIdentifier<DebugApi>, BigUint<DebugApi>>,
+>;
+
+struct RaisePoolSetup {
+    world: ScenarioWorld,
+    raise_pool: RaisePoolContract,
+    signer: Keypair,
+}
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+
+    blockchain.register_contract(RAISE_POOL_PATH_EXPR, raise_pool::ContractBuilder);
+    blockchain.register_contract(WALLET_DATABASE_PATH_EXPR, wallet_database::ContractBuilder);
+    blockchain
+}
+
+fn keypair(seed: u8) -> Keypair {
+    let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+    let public = PublicKey::from(&secret);
+    Keypair { secret, public }
+}
+
+// Chunked format: (signer_address + signature) for every signer.
+fn multi_signature(signers: &[&Keypair], payload: &[u8]) -> Vec<u8> {
+    let mut signature = Vec::new();
+    for signer in signers {
+        signature.extend(signer.public.to_bytes());
+        signature.extend(signer.sign(payload).to_bytes());
+    }
+    signature
+}
+
+fn address(expr: &str) -> Address {
+    AddressValue::from(expr).to_address()
+}
+
+fn nested(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = (bytes.len() as u32).to_be_bytes().to_vec();
+    encoded.extend_from_slice(bytes);
+    encoded
+}
+
+fn nested_biguint(value: u64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let first = bytes
+        .iter()
+        .position(|byte| *byte != 0)
+        .unwrap_or(bytes.len());
+    nested(&bytes[first..])
+}
+
+// timestamp + pool_id + caller + endpoint + arguments, as built by owner_call_payload.
+fn owner_call_payload(timestamp: u64, caller: &str, endpoint: &str, arguments: &[u8]) -> Vec<u8> {
+    let mut payload = timestamp.to_be_bytes().to_vec();
+    payload.extend(nested(POOL_ID));
+    payload.extend_from_slice(address(caller).as_bytes());
+    payload.extend(nested(endpoint.as_bytes()));
+    payload.extend_from_slice(arguments);
+    payload
+}
+
+// Single payment deposit without ambassadors nor max allocation.
+fn deposit_payload(timestamp: u64, caller: &str, amount: u64, deposit_id: &[u8]) -> Vec<u8> {
+    token_deposit_payload(timestamp, caller, TOKEN, FEE, amount, deposit_id)
+}
+
+fn token_deposit_payload(
+    timestamp: u64,
+    caller: &str,
+    token: &str,
+    fee: u64,
+    amount: u64,
+    deposit_id: &[u8],
+) -> Vec<u8> {
+    let mut payload = timestamp.to_be_bytes().to_vec();
+    payload.extend(nested(POOL_ID));
+    payload.extend_from_slice(address(caller).as_bytes());
+    payload.extend(nested(b"deposit"));
+    payload.extend(nested_biguint(fee));
+    payload.extend(nested_biguint(fee));
+    payload.extend(nested(token.as_bytes()));
+    payload.extend(nested_biguint(amount));
+    payload.extend(nested(deposit_id));
+    payload.push(0);
+    payload
+}
+
+fn overcommit_entries(overcommited: &[(&str, u64)]) -> OvercommitEntries {
+    let mut entries = MultiValueEncoded::new();
+    for (account, amount) in overcommited {
+        entries.push(
+            (
+                managed_address!(&address(account)),
+                managed_token_id_wrapped!(TOKEN),
+                managed_biguint!(*amount),
+            )
+                .into(),
+        );
+    }
+    entries
+}
+
+fn release_plan_payload(timestamp: u64, overcommited: &[(&str, u64)], is_final: bool) -> Vec<u8> {
+    let mut arguments = vec![is_final as u8];
+    for (account, amount) in overcommited {
+        arguments.extend_from_slice(address(account).as_bytes());
+        arguments.extend(nested(TOKEN.as_bytes()));
+        arguments.extend(nested_biguint(*amount));
+    }
+    owner_call_payload(timestamp, OWNER, "submitReleasePlan", &arguments)
+}
+
+fn payout_total(payout: &ReleasePayout<DebugApi>) -> u64 {
+    payout
+        .payments
+        .iter()
+        .map(|payment| payment.amount.to_u64().unwrap())
+        .sum()
+}
+
+impl RaisePoolSetup {
+    fn new() -> Self {
+        let mut world = world();
+        let signer = keypair(7);
+
+        let user_account = Account::new()
+            .nonce(1)
+            .balance(EGLD_BALANCE)
+            .esdt_balance(TOKEN_EXPR, INITIAL_BALANCE)
+            .esdt_balance(OTHER_TOKEN_EXPR, INITIAL_BALANCE);
+        world.set_state_step(
+            SetStateStep::new()
+                .put_account(OWNER, Account::new().nonce(1))
+                .put_account(USER, user_account.clone())
+                .put_account(OTHER_USER, user_account.clone())
+                .put_account(PARTNER, user_account)
+                .put_account(PLATFORM_FEE_WALLET, Account::new())
+                .put_account(GROUP_FEE_WALLET, Account::new())
+                .put_account(
+                    WALLET_DATABASE,
+                    Account::new()
+                        .code(world.code_expression(WALLET_DATABASE_PATH_EXPR))
+                        .owner(OWNER),
+                )
+                .new_address(OWNER, 1, RAISE_POOL)
+                .block_timestamp(DEPLOY_TIMESTAMP),
+        );
+
+        let raise_pool = WhiteboxContract::new(RAISE_POOL, raise_pool::contract_obj);
+        let raise_pool_code = world.code_expression(RAISE_POOL_PATH_EXPR);
+        let signer_address = Address::from(signer.public.to_bytes());
+        world.whitebox_deploy(
+            &raise_pool,
+            ScDeployStep::new().from(OWNER).code(raise_pool_code),
+            |sc| {
+                let mut payment_currencies = MultiValueEncoded::new();
+                payment_currencies.push((managed_token_id_wrapped!(TOKEN), TOKEN_DECIMALS).into());
+                payment_currencies
+                    .push((managed_token_id_wrapped!(OTHER_TOKEN), TOKEN_DECIMALS).into());
+                payment_currencies.push((EgldOrEsdtTokenIdentifier::egld(), 18).into());
+                sc.init(
+                    managed_address!(&address(OWNER)),
+                    managed_buffer!(POOL_ID),
+                    managed_biguint!(50),
+                    managed_biguint!(100),
+                    managed_biguint!(1),
+                    managed_biguint!(500),
+                    managed_biguint!(1),
+                    START_DATE,
+                    END_DATE,
+                    true,
+                    REFUND_DEADLINE,
+                    managed_address!(&address(PLATFORM_FEE_WALLET)),
+                    managed_address!(&address(GROUP_FEE_WALLET)),
+                    managed_address!(&signer_address),
+                    managed_address!(&address(WALLET_DATABASE)),
+                    ManagedByteArray::new_from_bytes(&[0u8; 32]),
+                    payment_currencies,
+                );
+            },
+        );
+
+        let wallet_database = WhiteboxContract::new(WALLET_DATABASE, wallet_database::contract_obj);
+        world.whitebox_call(&wallet_database, ScCallStep::new().from(OWNER), |sc| {
+            sc.whitelisted_wallets()
+                .add(&managed_address!(&address(USER)));
+            sc.whitelisted_wallets()
+                .add(&managed_address!(&address(OTHER_USER)));
+        });
+
+        Self {
+            world,
+            raise_pool,
+            signer,
+        }
+    }
+
+    fn set_timestamp(&mut self, timestamp: u64) {
+        self.world
+            .set_state_step(SetStateStep::new().block_timestamp(timestamp));
+    }
+
+    fn sign(&self, payload: &[u8]) -> Vec<u8> {
+        self.signer.sign(payload).to_bytes().to_vec()
+    }
+
+    fn deposit(
+        &mut self,
+        caller: &str,
+        amount: u64,
+        deposit_id: &[u8],
+        timestamp: u64,
+    ) -> TxResult {
+        let signature = self.sign(&deposit_payload(timestamp, caller, amount, deposit_id));
+        self.deposit_with_signature(caller, amount, deposit_id, timestamp, signature)
+    }
+
+    fn deposit_with_signature(
+        &mut self,
+        caller: &str,
+        amount: u64,
+        deposit_id: &[u8],
+        timestamp: u64,
+        signature: Vec<u8>,
+    ) -> TxResult {
+        let mut result = TxResult::empty();
+        let deposit_id = deposit_id.to_vec();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(TOKEN_EXPR, 0, amount)
+                .no_expect(),
+            |sc| {
+                sc.deposit(
+                    timestamp,
+                    managed_buffer!(&signature),
+                    managed_biguint!(FEE),
+                    managed_biguint!(FEE),
+                    managed_buffer!(&deposit_id),
+                    MultiValueEncoded::new(),
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    // Single payment deposit with one ambassador fee of FEE.
+    fn deposit_with_ambassador(
+        &mut self,
+        caller: &str,
+        amount: u64,
+        deposit_id: &[u8],
+    ) -> TxResult {
+        let mut payload = deposit_payload(SALE_TIMESTAMP, caller, amount, deposit_id);
+        payload.extend(nested_biguint(FEE));
+        payload.extend_from_slice(address(AMBASSADOR).as_bytes());
+        let signature = self.sign(&payload);
+        let mut result = TxResult::empty();
+        let deposit_id = deposit_id.to_vec();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new()
+                .from(caller)
+                .esdt_transfer(TOKEN_EXPR, 0, amount)
+                .no_expect(),
+            |sc| {
+                let mut ambassadors = MultiValueEncoded::new();
+                ambassadors.push(
+                    (
+                        managed_biguint!(FEE),
+                        managed_address!(&address(AMBASSADOR)),
+                    )
+                        .into(),
+                );
+                sc.deposit(
+                    SALE_TIMESTAMP,
+                    managed_buffer!(&signature),
+                    managed_biguint!(FEE),
+                    managed_biguint!(FEE),
+                    managed_buffer!(&deposit_id),
+                    ambassadors,
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    // The owner signature is made for signed_caller and signed_value, the call is made by
+    // caller with value.
+    fn enable_raise_pool(
+        &mut self,
+        caller: &str,
+        value: bool,
+        signed_caller: &str,
+        signed_value: bool,
+    ) -> TxResult {
+        let timestamp = SALE_TIMESTAMP;
+        let signature = self.sign(&owner_call_payload(
+            timestamp,
+            signed_caller,
+            "enableRaisePool",
+            &[signed_value as u8],
+        ));
+        let mut result = TxResult::empty();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new().from(caller).no_expect(),
+            |sc| {
+                sc.enable_raise_pool(value, timestamp, managed_buffer!(&signature));
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    fn release(&mut self, timestamp: u64) -> TxResult {
+        let signature = self.sign(&owner_call_payload(timestamp, OWNER, "release", &[]));
+        let mut result = TxResult::empty();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new().from(OWNER).no_expect(),
+            |sc| {
+                sc.release(timestamp, managed_buffer!(&signature));
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    fn set_withdrawal_penalty_schedule(&mut self, offset: u64, penalty: u64) {
+        let timestamp = SALE_TIMESTAMP;
+        let mut tiers = Vec::new();
+        tiers.extend(offset.to_be_bytes());
+        tiers.extend(penalty.to_be_bytes());
+        let signature = self.sign(&owner_call_payload(
+            timestamp,
+            OWNER,
+            "setWithdrawalPenaltySchedule",
+            &tiers,
+        ));
+        self.world
+            .whitebox_call(&self.raise_pool, ScCallStep::new().from(OWNER), |sc| {
+                let mut tiers = MultiValueEncoded::new();
+                tiers.push((offset, penalty).into());
+                sc.set_withdrawal_penalty_schedule(timestamp, managed_buffer!(&signature), tiers);
+            });
+    }
+
+    fn withdraw(&mut self, caller: &str, amount: u64, timestamp: u64) {
+        let mut arguments = nested(TOKEN.as_bytes());
+        arguments.extend(nested_biguint(amount));
+        let signature = self.sign(&owner_call_payload(
+            timestamp, caller, "withdraw", &arguments,
+        ));
+        self.world
+            .whitebox_call(&self.raise_pool, ScCallStep::new().from(caller), |sc| {
+                sc.withdraw(
+                    timestamp,
+                    managed_buffer!(&signature),
+                    managed_token_id_wrapped!(TOKEN),
+                    managed_biguint!(amount),
+                );
+            });
+    }
+
+    fn call(
+        &mut self,
+        caller: &str,
+        call: impl FnOnce(raise_pool::ContractObj<DebugApi>),
+    ) -> TxResult {
+        let mut result = TxResult::empty();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new().from(caller).no_expect(),
+            call,
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    fn enable_signed_with(&mut self, timestamp: u64, signature: Vec<u8>) -> TxResult {
+        self.call(OWNER, |sc| {
+            sc.enable_raise_pool(true, timestamp, managed_buffer!(&signature))
+        })
+    }
+
+    fn rotate_signer(&mut self, new_signer: &Keypair, grace_period: u64) {
+        let new_signer = Address::from(new_signer.public.to_bytes());
+        let mut arguments = new_signer.to_vec();
+        arguments.extend(grace_period.to_be_bytes());
+        let signature = self.sign(&owner_call_payload(
+            SALE_TIMESTAMP,
+            OWNER,
+            "rotateSigner",
+            &arguments,
+        ));
+        self.call(OWNER, |sc| {
+            sc.rotate_signer(
+                SALE_TIMESTAMP,
+                managed_buffer!(&signature),
+                managed_address!(&new_signer),
+                grace_period,
+            )
+        })
+        .assert_ok();
+    }
+
+    fn add_signer(&mut self, timestamp: u64, signer: &Keypair, signature: Vec<u8>) -> TxResult {
+        let signer = Address::from(signer.public.to_bytes());
+        self.call(OWNER, |sc| {
+            sc.add_signer(
+                timestamp,
+                managed_buffer!(&signature),
+                managed_address!(&signer),
+            )
+        })
+    }
+
+    fn add_round(
+        &mut self,
+        timestamp: u64,
+        start_date: u64,
+        end_date: u64,
+        max_deposit: u64,
+        cap: u64,
+    ) -> TxResult {
+        let mut arguments = nested(b"round");
+        arguments.extend(start_date.to_be_bytes());
+        arguments.extend(end_date.to_be_bytes());
+        arguments.extend(nested_biguint(1));
+        arguments.extend(nested_biguint(max_deposit));
+        arguments.extend(nested_biguint(cap));
+        arguments.push(0);
+        let signature = self.sign(&owner_call_payload(
+            timestamp, OWNER, "addRound", &arguments,
+        ));
+        let mut result = TxResult::empty();
+        self.world.whitebox_call_check(
+            &self.raise_pool,
+            ScCallStep::new().from(OWNER).no_expect(),
+            |sc| {
+                sc.add_round(
+                    timestamp,
+                    managed_buffer!(&signature),
+                    managed_buffer!(b"round"),
+                    start_date,
+                    end_date,
+                    managed_biguint!(1),
+                    managed_biguint!(max_deposit),
+                    managed_biguint!(cap),
+                    RoundEligibility::Everyone,
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    }
+
+    fn submit_release_plan(
+        &mut self,
+        timestamp: u64,
+        overcommited: &[(&str, u64)],
+        is_final: bool,
+    ) -> TxResult {
+        let signature = self.sign(&release_plan_payload(timestamp, overcommited, is_final));
+        self.submit_release_plan_with_signature(timestamp, overcommited, is_final, signature)
+    }
+
+    fn submit_release_plan_with_signature(
+        &mut self,
+        timestamp: u64,
+        overcommited: &[(&str, u64)],
+        is_final: bool,
+        signature: Vec<u8>,
+    ) -> TxResult {
+        self.call(OWNER, |sc| {
+            sc.submit_release_plan(
+                timestamp,
+                managed_buffer!(&signature),
+                is_final,
+                overcommit_entries(overcommited),
+            )
+        })
+    }
+
+    fn check_egld_balance(&mut self, account: &str, balance: u64) {
+        self.world
+            .check_state_step(CheckStateStep::new().put_account(
+                account,
+                CheckAccount::new().balance(balance.to_string().as_str()),
+            ));
+    }
+
+    fn check_token_balance(&mut self, account: &str, balance: u64) {
+        self.world
+            .check_state_step(CheckStateStep::new().put_account(
+                account,
+                CheckAccount::new().esdt_balance(TOKEN_EXPR, balance),
+            ));
+    }
+}
+
+#[test]
+fn owner_signature_is_bound_to_arguments_and_caller() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+
+    setup
+        .enable_raise_pool(OWNER, true, OWNER, false)
+        .assert_error(10, "invalid signature");
+    setup
+        .enable_raise_pool(USER, true, OWNER, true)
+        .assert_error(10, "invalid signature");
+    setup
+        .enable_raise_pool(USER, true, USER, true)
+        .assert_user_error("Only owner can call this function");
+    setup
+        .enable_raise_pool(OWNER, true, OWNER, true)
+        .assert_ok();
+}
+
+#[test]
+fn owner_signature_cannot_be_replayed() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+
+    setup
+        .enable_raise_pool(OWNER, true, OWNER, true)
+        .assert_ok();
+    setup
+        .enable_raise_pool(OWNER, true, OWNER, true)
+        .assert_user_error("Signature already used");
+}
+
+#[test]
+fn deposit_signature_is_bound_to_the_amount() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+
+    let signature = setup.sign(&deposit_payload(
+        SALE_TIMESTAMP,
+        USER,
+        10 * UNIT,
+        b"deposit-1",
+    ));
+    setup
+        .deposit_with_signature(USER, 20 * UNIT, b"deposit-1", SALE_TIMESTAMP, signature)
+        .assert_error(10, "invalid signature");
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+}
+
+#[test]
+fn user_refund_signature_cannot_be_replayed() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        USER,
+        "userRefund",
+        &nested(TOKEN.as_bytes()),
+    ));
+    let user_refund = |setup: &mut RaisePoolSetup| {
+        let mut result = TxResult::empty();
+        setup.world.whitebox_call_check(
+            &setup.raise_pool,
+            ScCallStep::new().from(USER).no_expect(),
+            |sc| {
+                sc.user_refund(
+                    SALE_TIMESTAMP,
+                    managed_buffer!(&signature),
+                    managed_token_id_wrapped!(TOKEN),
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    };
+
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    user_refund(&mut setup).assert_ok();
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+    user_refund(&mut setup).assert_user_error("Signature already used");
+    // Platform fees are kept by userRefund, only the first deposit was refunded.
+    setup.check_token_balance(USER, INITIAL_BALANCE - 10 * UNIT - FEE);
+}
+
+#[test]
+fn duplicate_deposit_id_is_rejected() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup.set_timestamp(SALE_TIMESTAMP + 10);
+    setup
+        .deposit(OTHER_USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP + 10)
+        .assert_user_error("Deposit ID already used");
+    setup.check_token_balance(OTHER_USER, INITIAL_BALANCE);
+}
+
+#[test]
+fn claim_refund_returns_the_whole_deposit() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+
+    setup.set_timestamp(END_DATE + 1);
+    setup.world.whitebox_call_check(
+        &setup.raise_pool,
+        ScCallStep::new().from(USER).no_expect(),
+        |sc| sc.claim_refund(),
+        |tx_result| tx_result.assert_user_error("Refund claims are not open"),
+    );
+
+    setup.set_timestamp(END_DATE + REFUND_CLAIM_GRACE_PERIOD + 1);
+    setup
+        .world
+        .whitebox_call(&setup.raise_pool, ScCallStep::new().from(USER), |sc| {
+            sc.claim_refund()
+        });
+    setup.check_token_balance(USER, INITIAL_BALANCE);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        assert!(sc.deposited_currencies(&user).is_empty());
+        assert!(!sc.addresses().contains(&user));
+        assert_eq!(sc.total_amount().get(), managed_biguint!(0));
+    });
+}
+
+#[test]
+fn oversubscribed_pool_is_allocated_pro_rata() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        OWNER,
+        "setOversubscriptionEnabled",
+        &[1],
+    ));
+    setup
+        .world
+        .whitebox_call(&setup.raise_pool, ScCallStep::new().from(OWNER), |sc| {
+            sc.set_oversubscription_enabled(SALE_TIMESTAMP, managed_buffer!(&signature), true)
+        });
+
+    // 100 and 300 net against a hard cap of 100: every wallet keeps a quarter.
+    setup
+        .deposit(USER, 101 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 301 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+
+    setup.set_timestamp(END_DATE + 1);
+    setup.release(END_DATE + 1).assert_ok();
+
+    setup.check_token_balance(USER, INITIAL_BALANCE - 101 * UNIT + 101 * UNIT * 3 / 4);
+    setup.check_token_balance(
+        OTHER_USER,
+        INITIAL_BALANCE - 301 * UNIT + 301 * UNIT * 3 / 4,
+    );
+    setup.check_token_balance(PLATFORM_FEE_WALLET, FEE / 4 + FEE / 4);
+    setup.check_token_balance(GROUP_FEE_WALLET, FEE / 4 + FEE / 4);
+    setup.check_token_balance(OWNER, 100 * UNIT);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let unit = managed_biguint!(1_000_000_000_000_000_000);
+        let user_allocation = sc.allocations(&managed_address!(&address(USER))).get();
+        assert_eq!(user_allocation.allocation, &unit * 25u32);
+        let other_allocation = sc
+            .allocations(&managed_address!(&address(OTHER_USER)))
+            .get();
+        assert_eq!(other_allocation.allocation, &unit * 75u32);
+    });
+}
+
+#[test]
+fn partial_withdraw_pays_the_penalty() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup.set_withdrawal_penalty_schedule(0, 1_000);
+    setup
+        .deposit(USER, 101 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+
+    // Half of the deposit with a 10% penalty.
+    let withdraw_timestamp = SALE_TIMESTAMP + 50;
+    let amount = 101 * UNIT / 2;
+    setup.set_timestamp(withdraw_timestamp);
+    setup.withdraw(USER, amount, withdraw_timestamp);
+
+    let penalty = amount / 10;
+    setup.check_token_balance(USER, INITIAL_BALANCE - 101 * UNIT + amount - penalty);
+    setup.check_token_balance(PLATFORM_FEE_WALLET, penalty);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        let token = managed_token_id_wrapped!(TOKEN);
+        assert_eq!(
+            sc.deposited_amount(&user, &token).get(),
+            managed_biguint!(101 * UNIT - amount)
+        );
+        assert_eq!(
+            sc.address_platform_fee(&user, &token).get(),
+            managed_biguint!(FEE / 2)
+        );
+    });
+}
+
+#[test]
+fn tiny_deposit_does_not_reset_the_penalty_of_older_deposits() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    // Free during the first 100 seconds after a deposit, 10% afterwards.
+    setup.set_withdrawal_penalty_schedule(100, 1_000);
+    setup
+        .deposit(USER, 51 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+
+    let late_timestamp = SALE_TIMESTAMP + 200;
+    setup.set_timestamp(late_timestamp);
+    setup
+        .deposit(USER, 2 * UNIT, b"deposit-2", late_timestamp)
+        .assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let penalty = sc.get_withdrawal_penalty(
+            managed_address!(&address(USER)),
+            managed_token_id_wrapped!(TOKEN),
+            managed_biguint!(53 * UNIT),
+        );
+        assert_eq!(penalty, managed_biguint!(51 * UNIT / 10));
+    });
+
+    // Only the tiny deposit is still in its free window.
+    setup.withdraw(USER, 53 * UNIT, late_timestamp);
+
+    let penalty = 51 * UNIT / 10;
+    setup.check_token_balance(USER, INITIAL_BALANCE - penalty);
+    setup.check_token_balance(PLATFORM_FEE_WALLET, penalty);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        let token = managed_token_id_wrapped!(TOKEN);
+        assert!(sc.deposit_tranches(&user, &token).is_empty());
+        assert!(!sc.addresses().contains(&user));
+    });
+}
+
+#[test]
+fn cancelled_pool_is_refunded() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 20 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        OWNER,
+        "cancelPool",
+        &[],
+    ));
+    setup
+        .world
+        .whitebox_call(&setup.raise_pool, ScCallStep::new().from(OWNER), |sc| {
+            sc.cancel_pool(SALE_TIMESTAMP, managed_buffer!(&signature))
+        });
+
+    let refund_timestamp = SALE_TIMESTAMP + 10;
+    setup.set_timestamp(refund_timestamp);
+    let signature = setup.sign(&owner_call_payload(refund_timestamp, OWNER, "refund", &[]));
+    setup
+        .world
+        .whitebox_call(&setup.raise_pool, ScCallStep::new().from(OWNER), |sc| {
+            sc.refund(refund_timestamp, managed_buffer!(&signature));
+        });
+
+    setup.check_token_balance(USER, INITIAL_BALANCE);
+    setup.check_token_balance(OTHER_USER, INITIAL_BALANCE);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert_eq!(sc.pool_status(), PoolStatus::Cancelled);
+        assert!(sc.addresses().is_empty());
+        assert_eq!(sc.total_amount().get(), managed_biguint!(0));
+    });
+}
+
+#[test]
+fn round_limits_are_enforced() {
+    let mut setup = RaisePoolSetup::new();
+    setup
+        .add_round(DEPLOY_TIMESTAMP, START_DATE, SALE_TIMESTAMP + 100, 30, 40)
+        .assert_ok();
+    setup
+        .add_round(DEPLOY_TIMESTAMP, SALE_TIMESTAMP + 50, END_DATE, 100, 100)
+        .assert_user_error("Rounds cannot overlap");
+
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 32 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_user_error("Round allocation exceeded");
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 12 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_user_error("Round cap exceeded");
+    setup
+        .deposit(OTHER_USER, 11 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+
+    setup.set_timestamp(SALE_TIMESTAMP + 100);
+    setup
+        .deposit(OTHER_USER, 2 * UNIT, b"deposit-3", SALE_TIMESTAMP + 100)
+        .assert_user_error("No active round");
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let unit = managed_biguint!(1_000_000_000_000_000_000);
+        assert_eq!(sc.round_total(1).get(), &unit * 40u32);
+        assert_eq!(
+            sc.round_deposited_amount(1, &managed_address!(&address(USER)))
+                .get(),
+            &unit * 30u32
+        );
+    });
+}
+
+#[test]
+fn rounds_added_during_the_sale_start_in_the_future() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .add_round(SALE_TIMESTAMP, START_DATE, SALE_TIMESTAMP + 100, 10, 10)
+        .assert_user_error("Round start date must be in the future");
+    setup
+        .add_round(SALE_TIMESTAMP, SALE_TIMESTAMP, SALE_TIMESTAMP + 100, 10, 10)
+        .assert_user_error("Round start date must be in the future");
+    setup
+        .add_round(
+            SALE_TIMESTAMP,
+            SALE_TIMESTAMP + 1,
+            SALE_TIMESTAMP + 100,
+            10,
+            10,
+        )
+        .assert_ok();
+}
+
+#[test]
+fn failed_pool_cannot_be_released() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 10 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .release(SALE_TIMESTAMP)
+        .assert_user_error("Pool cannot be released");
+
+    // Below the soft cap the pool can only be refunded, even during the claim grace period.
+    setup.set_timestamp(END_DATE + 1);
+    setup
+        .release(END_DATE + 1)
+        .assert_user_error("Pool cannot be released");
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert_eq!(sc.pool_status(), PoolStatus::SoftCapFailed);
+        assert!(!sc.release_started().get());
+    });
+    setup.check_token_balance(PLATFORM_FEE_WALLET, 0);
+}
+
+#[test]
+fn signed_allocation_replaces_max_deposit() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+
+    let deposit_with_allocation = |setup: &mut RaisePoolSetup, amount: u64, deposit_id: &[u8]| {
+        let mut payload = deposit_payload(SALE_TIMESTAMP, USER, amount, deposit_id);
+        payload.pop();
+        payload.push(1);
+        payload.extend(nested_biguint(20));
+        let signature = setup.sign(&payload);
+        let mut result = TxResult::empty();
+        setup.world.whitebox_call_check(
+            &setup.raise_pool,
+            ScCallStep::new()
+                .from(USER)
+                .esdt_transfer(TOKEN_EXPR, 0, amount)
+                .no_expect(),
+            |sc| {
+                sc.deposit_with_allocation(
+                    SALE_TIMESTAMP,
+                    managed_buffer!(&signature),
+                    managed_biguint!(FEE),
+                    managed_biguint!(FEE),
+                    managed_buffer!(deposit_id),
+                    managed_biguint!(20),
+                    MultiValueEncoded::new(),
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    };
+
+    deposit_with_allocation(&mut setup, 22 * UNIT, b"deposit-1")
+        .assert_user_error("Payment amount too high");
+    deposit_with_allocation(&mut setup, 11 * UNIT, b"deposit-1").assert_ok();
+    // The stored limit keeps applying to plain deposits of the wallet.
+    setup
+        .deposit(USER, 12 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_user_error("Payment amount too high");
+    setup
+        .deposit(USER, 11 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        assert_eq!(sc.wallet_max_allocation(&user).get(), managed_biguint!(20));
+    });
+}
+
+fn enable_payload(timestamp: u64) -> Vec<u8> {
+    owner_call_payload(timestamp, OWNER, "enableRaisePool", &[1])
+}
+
+fn add_signer_payload(timestamp: u64, signer: &Keypair) -> Vec<u8> {
+    owner_call_payload(timestamp, OWNER, "addSigner", &signer.public.to_bytes())
+}
+
+#[test]
+fn rotated_signer_shares_plain_signatures_during_the_grace_period() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let old_signer = keypair(7);
+    let new_signer = keypair(8);
+    setup.rotate_signer(&new_signer, 100);
+
+    let signature = old_signer.sign(&enable_payload(SALE_TIMESTAMP + 1));
+    setup.set_timestamp(SALE_TIMESTAMP + 1);
+    setup
+        .enable_signed_with(SALE_TIMESTAMP + 1, signature.to_bytes().to_vec())
+        .assert_ok();
+    let signature = new_signer.sign(&enable_payload(SALE_TIMESTAMP + 2));
+    setup.set_timestamp(SALE_TIMESTAMP + 2);
+    setup
+        .enable_signed_with(SALE_TIMESTAMP + 2, signature.to_bytes().to_vec())
+        .assert_ok();
+}
+
+#[test]
+fn previous_signer_expires_with_the_grace_period() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let old_signer = keypair(7);
+    let new_signer = keypair(8);
+    setup.rotate_signer(&new_signer, 100);
+
+    let timestamp = SALE_TIMESTAMP + 100;
+    setup.set_timestamp(timestamp);
+    let signature = old_signer.sign(&enable_payload(timestamp));
+    setup
+        .enable_signed_with(timestamp, signature.to_bytes().to_vec())
+        .assert_error(10, "invalid signature");
+    let signature = multi_signature(&[&old_signer], &enable_payload(timestamp));
+    setup
+        .enable_signed_with(timestamp, signature)
+        .assert_user_error("Signer not authorized");
+    let signature = new_signer.sign(&enable_payload(timestamp));
+    setup
+        .enable_signed_with(timestamp, signature.to_bytes().to_vec())
+        .assert_ok();
+}
+
+#[test]
+fn zero_grace_period_revokes_the_previous_signer() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let old_signer = keypair(7);
+    setup.rotate_signer(&keypair(8), 0);
+
+    let signature = old_signer.sign(&enable_payload(SALE_TIMESTAMP + 1));
+    setup.set_timestamp(SALE_TIMESTAMP + 1);
+    setup
+        .enable_signed_with(SALE_TIMESTAMP + 1, signature.to_bytes().to_vec())
+        .assert_error(10, "invalid signature");
+}
+
+#[test]
+fn previous_signer_does_not_count_for_high_value_calls() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let old_signer = keypair(7);
+    let new_signer = keypair(8);
+    let added_signer = keypair(9);
+    setup.rotate_signer(&new_signer, 100);
+
+    let timestamp = SALE_TIMESTAMP + 1;
+    setup.set_timestamp(timestamp);
+    let payload = add_signer_payload(timestamp, &added_signer);
+    let signature = old_signer.sign(&payload).to_bytes().to_vec();
+    setup
+        .add_signer(timestamp, &added_signer, signature)
+        .assert_error(10, "invalid signature");
+    let signature = multi_signature(&[&old_signer], &payload);
+    setup
+        .add_signer(timestamp, &added_signer, signature)
+        .assert_user_error("Signer not authorized");
+    let signature = new_signer.sign(&payload).to_bytes().to_vec();
+    setup
+        .add_signer(timestamp, &added_signer, signature)
+        .assert_ok();
+}
+
+#[test]
+fn high_value_calls_require_the_signer_threshold() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let signer = keypair(7);
+    let second_signer = keypair(8);
+    let unknown_signer = keypair(9);
+
+    let payload = add_signer_payload(SALE_TIMESTAMP, &second_signer);
+    let signature = setup.sign(&payload);
+    setup
+        .add_signer(SALE_TIMESTAMP, &second_signer, signature)
+        .assert_ok();
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        OWNER,
+        "setSignerThreshold",
+        &2u32.to_be_bytes(),
+    ));
+    setup
+        .call(OWNER, |sc| {
+            sc.set_signer_threshold(SALE_TIMESTAMP, managed_buffer!(&signature), 2)
+        })
+        .assert_ok();
+
+    let timestamp = SALE_TIMESTAMP + 1;
+    setup.set_timestamp(timestamp);
+    let payload = owner_call_payload(timestamp, OWNER, "cancelPool", &[]);
+    let mut cancel_pool = |signature: Vec<u8>| {
+        setup.call(OWNER, |sc| {
+            sc.cancel_pool(timestamp, managed_buffer!(&signature))
+        })
+    };
+    cancel_pool(signer.sign(&payload).to_bytes().to_vec())
+        .assert_user_error("Not enough signatures");
+    cancel_pool(multi_signature(&[&signer], &payload)).assert_user_error("Not enough signatures");
+    cancel_pool(multi_signature(&[&signer, &signer], &payload))
+        .assert_user_error("Duplicate signer");
+    cancel_pool(multi_signature(&[&signer, &unknown_signer], &payload))
+        .assert_user_error("Signer not authorized");
+    cancel_pool(multi_signature(&[&signer, &second_signer], &payload)).assert_ok();
+
+    // Regular owner calls keep working with a single signature.
+    let signature = signer.sign(&enable_payload(timestamp));
+    setup
+        .enable_signed_with(timestamp, signature.to_bytes().to_vec())
+        .assert_user_error("Pool is cancelled");
+}
+
+#[test]
+fn simulated_release_matches_the_release() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 31 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+    setup.set_timestamp(END_DATE + 1);
+
+    // Dry run of a plan that is not submitted yet.
+    let overcommited = [(OTHER_USER, 10 * UNIT)];
+    let mut simulated = (0, 0, 0, 0);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let simulation = sc.simulate_release(overcommit_entries(&overcommited));
+        assert_eq!(simulation.overcommit_refunds.len(), 1);
+        let refund = simulation.overcommit_refunds.get(0);
+        assert_eq!(refund.recipient, managed_address!(&address(OTHER_USER)));
+        assert_eq!(
+            simulation.platform_fees.recipient,
+            managed_address!(&address(PLATFORM_FEE_WALLET))
+        );
+        assert_eq!(simulation.estimated_calls, 1);
+        simulated = (
+            payout_total(&refund),
+            payout_total(&simulation.platform_fees),
+            payout_total(&simulation.group_fees),
+            payout_total(&simulation.retrieve),
+        );
+
+        let without_plan = sc.simulate_release(MultiValueEncoded::new());
+        assert!(without_plan.overcommit_refunds.is_empty());
+    });
+    let (refund, platform_fee, group_fee, retrieved) = simulated;
+    assert!(refund > 0);
+
+    // Once submitted, the stored plan gives the same result.
+    setup
+        .submit_release_plan(END_DATE + 1, &overcommited, true)
+        .assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let simulation = sc.simulate_release(MultiValueEncoded::new());
+        assert_eq!(payout_total(&simulation.overcommit_refunds.get(0)), refund);
+        assert_eq!(payout_total(&simulation.retrieve), retrieved);
+    });
+
+    setup.release(END_DATE + 1).assert_ok();
+    setup.check_token_balance(OTHER_USER, INITIAL_BALANCE - 31 * UNIT + refund);
+    setup.check_token_balance(PLATFORM_FEE_WALLET, platform_fee);
+    setup.check_token_balance(GROUP_FEE_WALLET, group_fee);
+    setup.check_token_balance(OWNER, retrieved);
+}
+
+#[test]
+fn fees_are_claimed_and_swept_in_fee_claim_mode() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        OWNER,
+        "setFeeClaimMode",
+        &[1],
+    ));
+    setup
+        .call(OWNER, |sc| {
+            sc.set_fee_claim_mode(SALE_TIMESTAMP, managed_buffer!(&signature), true)
+        })
+        .assert_ok();
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 31 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+
+    // The release only sends the net amount, the fees stay in the pool.
+    setup.set_timestamp(END_DATE + 1);
+    setup.release(END_DATE + 1).assert_ok();
+    setup.check_token_balance(OWNER, 60 * UNIT);
+    setup.check_token_balance(PLATFORM_FEE_WALLET, 0);
+    setup.check_token_balance(GROUP_FEE_WALLET, 0);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let claimable: Vec<_> = sc
+            .get_claimable_fees(managed_address!(&address(PLATFORM_FEE_WALLET)))
+            .into_iter()
+            .map(|claimable| claimable.into_tuple())
+            .collect();
+        assert_eq!(claimable.len(), 1);
+        let (token, amount) = &claimable[0];
+        assert_eq!(*token, managed_token_id_wrapped!(TOKEN));
+        assert_eq!(*amount, managed_biguint!(2 * FEE));
+    });
+
+    setup
+        .call(PLATFORM_FEE_WALLET, |sc| sc.claim_fees())
+        .assert_ok();
+    setup.check_token_balance(PLATFORM_FEE_WALLET, 2 * FEE);
+    setup
+        .call(PLATFORM_FEE_WALLET, |sc| sc.claim_fees())
+        .assert_user_error("Nothing to claim");
+    setup
+        .call(USER, |sc| sc.claim_fees())
+        .assert_user_error("Nothing to claim");
+
+    // The group fees are left unclaimed and swept to the platform wallet after the deadline.
+    let sweep = |setup: &mut RaisePoolSetup, timestamp: u64| {
+        let signature = setup.sign(&owner_call_payload(
+            timestamp,
+            OWNER,
+            "sweepUnclaimedFees",
+            &[],
+        ));
+        setup.call(OWNER, |sc| {
+            sc.sweep_unclaimed_fees(timestamp, managed_buffer!(&signature))
+        })
+    };
+    sweep(&mut setup, END_DATE + 1).assert_user_error("Fee claim period not over");
+    let after_deadline = END_DATE + 2 + FEE_CLAIM_PERIOD;
+    setup.set_timestamp(after_deadline);
+    sweep(&mut setup, after_deadline).assert_ok();
+    setup.check_token_balance(PLATFORM_FEE_WALLET, 4 * FEE);
+    setup
+        .call(GROUP_FEE_WALLET, |sc| sc.claim_fees())
+        .assert_user_error("Unclaimed fees were swept");
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert!(sc
+            .get_claimable_fees(managed_address!(&address(GROUP_FEE_WALLET)))
+            .is_empty());
+    });
+}
+
+#[test]
+fn release_follows_the_signed_release_plan() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit(OTHER_USER, 31 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+    let second_signer = keypair(8);
+    let signature = setup.sign(&add_signer_payload(SALE_TIMESTAMP, &second_signer));
+    setup
+        .add_signer(SALE_TIMESTAMP, &second_signer, signature)
+        .assert_ok();
+
+    let timestamp = END_DATE + 1;
+    setup.set_timestamp(timestamp);
+    let first_chunk = [(USER, 31 * UNIT)];
+    setup
+        .submit_release_plan(timestamp, &first_chunk, false)
+        .assert_ok();
+    setup
+        .release(timestamp)
+        .assert_user_error("Release plan not finalized");
+    let mut first_hash = [0u8; 32];
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        first_hash = sc.release_plan_hash().get().to_byte_array();
+    });
+
+    // The entries are covered by the signature.
+    let second_chunk = [(OTHER_USER, 10 * UNIT)];
+    let signature = setup.sign(&release_plan_payload(
+        timestamp,
+        &[(OTHER_USER, 20 * UNIT)],
+        true,
+    ));
+    let result =
+        setup.submit_release_plan_with_signature(timestamp, &second_chunk, true, signature);
+    assert_ne!(result.result_status, 0);
+
+    // With a threshold of 2 the plan needs both signers.
+    let signature = setup.sign(&owner_call_payload(
+        timestamp,
+        OWNER,
+        "setSignerThreshold",
+        &2u32.to_be_bytes(),
+    ));
+    setup
+        .call(OWNER, |sc| {
+            sc.set_signer_threshold(timestamp, managed_buffer!(&signature), 2)
+        })
+        .assert_ok();
+    let payload = release_plan_payload(timestamp, &second_chunk, true);
+    let signature = setup.sign(&payload);
+    setup
+        .submit_release_plan_with_signature(timestamp, &second_chunk, true, signature)
+        .assert_user_error("Not enough signatures");
+    let signature = multi_signature(&[&setup.signer, &second_signer], &payload);
+    setup
+        .submit_release_plan_with_signature(timestamp, &second_chunk, true, signature)
+        .assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert_eq!(sc.release_plan().len(), 2);
+        assert_ne!(sc.release_plan_hash().get().to_byte_array(), first_hash);
+        assert!(sc.release_plan_finalized().get());
+    });
+    let payload = release_plan_payload(timestamp, &[(USER, UNIT)], true);
+    let signature = multi_signature(&[&setup.signer, &second_signer], &payload);
+    setup
+        .submit_release_plan_with_signature(timestamp, &[(USER, UNIT)], true, signature)
+        .assert_user_error("Release plan already finalized");
+
+    // USER is refunded in full, OTHER_USER for 10 of the 31 deposited units.
+    let payload = owner_call_payload(timestamp, OWNER, "release", &[]);
+    let signature = multi_signature(&[&setup.signer, &second_signer], &payload);
+    setup
+        .call(OWNER, |sc| {
+            sc.release(timestamp, managed_buffer!(&signature));
+        })
+        .assert_ok();
+    setup.check_token_balance(USER, INITIAL_BALANCE);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert_eq!(sc.release_state().get(), ReleaseState::Retrieved);
+        assert_eq!(
+            sc.deposited_amount(
+                &managed_address!(&address(OTHER_USER)),
+                &managed_token_id_wrapped!(TOKEN)
+            )
+            .get(),
+            managed_biguint!(21 * UNIT)
+        );
+    });
+}
+
+#[test]
+fn egld_deposits_are_refunded_and_released() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let deposit_egld = |setup: &mut RaisePoolSetup, caller: &str, deposit_id: &[u8]| {
+        let amount = 5 * EGLD_UNIT;
+        let signature = setup.sign(&token_deposit_payload(
+            SALE_TIMESTAMP,
+            caller,
+            EGLD,
+            EGLD_FEE,
+            amount,
+            deposit_id,
+        ));
+        let mut result = TxResult::empty();
+        setup.world.whitebox_call_check(
+            &setup.raise_pool,
+            ScCallStep::new()
+                .from(caller)
+                .egld_value(amount)
+                .no_expect(),
+            |sc| {
+                sc.deposit(
+                    SALE_TIMESTAMP,
+                    managed_buffer!(&signature),
+                    managed_biguint!(EGLD_FEE),
+                    managed_biguint!(EGLD_FEE),
+                    managed_buffer!(deposit_id),
+                    MultiValueEncoded::new(),
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    };
+    deposit_egld(&mut setup, USER, b"deposit-1").assert_ok();
+    deposit_egld(&mut setup, OTHER_USER, b"deposit-2").assert_ok();
+    setup
+        .deposit(USER, 47 * UNIT, b"deposit-3", SALE_TIMESTAMP)
+        .assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        assert_eq!(
+            sc.deposited_amount(&managed_address!(&address(USER)), &egld)
+                .get(),
+            managed_biguint!(5 * EGLD_UNIT)
+        );
+        assert_eq!(
+            sc.total_amount_currency(&egld).get(),
+            managed_biguint!(5 * EGLD_UNIT) * 2u32
+        );
+    });
+
+    // userRefund sends the EGLD back, without the platform fee.
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        OTHER_USER,
+        "userRefund",
+        &nested(EGLD.as_bytes()),
+    ));
+    setup
+        .call(OTHER_USER, |sc| {
+            sc.user_refund(
+                SALE_TIMESTAMP,
+                managed_buffer!(&signature),
+                EgldOrEsdtTokenIdentifier::egld(),
+            )
+        })
+        .assert_ok();
+    setup.check_egld_balance(OTHER_USER, EGLD_BALANCE - EGLD_FEE);
+
+    // 4 EGLD and 46 USDC net reach the soft cap of 50 once normalized to 18 decimals.
+    setup.set_timestamp(END_DATE + 1);
+    setup.release(END_DATE + 1).assert_ok();
+    setup.check_egld_balance(OWNER, 4 * EGLD_UNIT);
+    setup.check_token_balance(OWNER, 46 * UNIT);
+    setup.check_egld_balance(PLATFORM_FEE_WALLET, 2 * EGLD_FEE);
+    setup.check_token_balance(PLATFORM_FEE_WALLET, FEE);
+    setup.check_egld_balance(GROUP_FEE_WALLET, EGLD_FEE);
+    setup.check_token_balance(GROUP_FEE_WALLET, FEE);
+}
+
+#[test]
+fn multi_payment_deposit_is_accepted_or_rejected_in_full() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let deposit_multi =
+        |setup: &mut RaisePoolSetup, caller: &str, amounts: (u64, u64), deposit_id: &[u8]| {
+            let mut payload = SALE_TIMESTAMP.to_be_bytes().to_vec();
+            payload.extend(nested(POOL_ID));
+            payload.extend_from_slice(address(caller).as_bytes());
+            payload.extend(nested(b"deposit"));
+            for (token, amount) in [(TOKEN, amounts.0), (OTHER_TOKEN, amounts.1)] {
+                payload.extend(nested_biguint(FEE));
+                payload.extend(nested_biguint(FEE));
+                payload.extend(nested(token.as_bytes()));
+                payload.extend(nested_biguint(amount));
+            }
+            payload.extend(nested(deposit_id));
+            payload.push(0);
+            let signature = setup.sign(&payload);
+            let mut result = TxResult::empty();
+            setup.world.whitebox_call_check(
+                &setup.raise_pool,
+                ScCallStep::new()
+                    .from(caller)
+                    .esdt_transfer(TOKEN_EXPR, 0, amounts.0)
+                    .esdt_transfer(OTHER_TOKEN_EXPR, 0, amounts.1)
+                    .no_expect(),
+                |sc| {
+                    let mut fees = ManagedVec::new();
+                    fees.push(managed_biguint!(FEE));
+                    fees.push(managed_biguint!(FEE));
+                    sc.deposit_multi(
+                        SALE_TIMESTAMP,
+                        managed_buffer!(&signature),
+                        fees.clone(),
+                        fees,
+                        managed_buffer!(deposit_id),
+                        None,
+                        MultiValueEncoded::new(),
+                    );
+                },
+                |tx_result| result = tx_result,
+            );
+            result
+        };
+
+    deposit_multi(&mut setup, USER, (31 * UNIT, 21 * UNIT), b"deposit-1").assert_ok();
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        assert_eq!(
+            sc.deposited_amount(&user, &managed_token_id_wrapped!(TOKEN))
+                .get(),
+            managed_biguint!(31 * UNIT)
+        );
+        assert_eq!(
+            sc.deposited_amount(&user, &managed_token_id_wrapped!(OTHER_TOKEN))
+                .get(),
+            managed_biguint!(21 * UNIT)
+        );
+        assert_eq!(
+            sc.deposits(&managed_buffer!(b"deposit-1"))
+                .get()
+                .payments
+                .len(),
+            2
+        );
+    });
+
+    // 40 + 20 more would exceed the hard cap of 100: neither payment is kept.
+    deposit_multi(&mut setup, OTHER_USER, (41 * UNIT, 21 * UNIT), b"deposit-2")
+        .assert_user_error("Hard cap threshold would be exceeded");
+    setup.check_token_balance(OTHER_USER, INITIAL_BALANCE);
+    setup
+        .world
+        .check_state_step(CheckStateStep::new().put_account(
+            OTHER_USER,
+            CheckAccount::new().esdt_balance(OTHER_TOKEN_EXPR, INITIAL_BALANCE),
+        ));
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert!(!sc
+            .addresses()
+            .contains(&managed_address!(&address(OTHER_USER))));
+    });
+}
+
+#[test]
+fn deposit_for_is_booked_for_the_beneficiary() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    let deposit_for = |setup: &mut RaisePoolSetup, beneficiary: &str, deposit_id: &[u8]| {
+        let amount = 31 * UNIT;
+        let mut payload = SALE_TIMESTAMP.to_be_bytes().to_vec();
+        payload.extend(nested(POOL_ID));
+        payload.extend_from_slice(address(PARTNER).as_bytes());
+        payload.extend(nested(b"depositFor"));
+        payload.extend_from_slice(address(beneficiary).as_bytes());
+        payload.extend(nested_biguint(FEE));
+        payload.extend(nested_biguint(FEE));
+        payload.extend(nested(TOKEN.as_bytes()));
+        payload.extend(nested_biguint(amount));
+        payload.extend(nested(deposit_id));
+        payload.push(0);
+        let signature = setup.sign(&payload);
+        let mut result = TxResult::empty();
+        setup.world.whitebox_call_check(
+            &setup.raise_pool,
+            ScCallStep::new()
+                .from(PARTNER)
+                .esdt_transfer(TOKEN_EXPR, 0, amount)
+                .no_expect(),
+            |sc| {
+                sc.deposit_for(
+                    managed_address!(&address(beneficiary)),
+                    SALE_TIMESTAMP,
+                    managed_buffer!(&signature),
+                    managed_biguint!(FEE),
+                    managed_biguint!(FEE),
+                    managed_buffer!(deposit_id),
+                    None,
+                    MultiValueEncoded::new(),
+                );
+            },
+            |tx_result| result = tx_result,
+        );
+        result
+    };
+
+    // The partner itself is not registered, the beneficiary has to be.
+    deposit_for(&mut setup, PARTNER, b"deposit-1").assert_user_error("Wallet not registered");
+    deposit_for(&mut setup, USER, b"deposit-2").assert_ok();
+    setup.check_token_balance(PARTNER, INITIAL_BALANCE - 31 * UNIT);
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let user = managed_address!(&address(USER));
+        let partner = managed_address!(&address(PARTNER));
+        let token = managed_token_id_wrapped!(TOKEN);
+        assert_eq!(
+            sc.deposited_amount(&user, &token).get(),
+            managed_biguint!(31 * UNIT)
+        );
+        assert!(sc.deposited_amount(&partner, &token).is_empty());
+        assert!(!sc.addresses().contains(&partner));
+        let deposit = sc.deposits(&managed_buffer!(b"deposit-2")).get();
+        assert_eq!(deposit.depositor, user);
+        assert_eq!(deposit.payer, partner);
+    });
+
+    // Refunds go to the beneficiary.
+    let signature = setup.sign(&owner_call_payload(
+        SALE_TIMESTAMP,
+        USER,
+        "userRefund",
+        &nested(TOKEN.as_bytes()),
+    ));
+    setup
+        .call(USER, |sc| {
+            sc.user_refund(
+                SALE_TIMESTAMP,
+                managed_buffer!(&signature),
+                managed_token_id_wrapped!(TOKEN),
+            )
+        })
+        .assert_ok();
+    setup.check_token_balance(USER, INITIAL_BALANCE + 31 * UNIT - FEE);
+    setup.check_token_balance(PARTNER, INITIAL_BALANCE - 31 * UNIT);
+}
+
+#[test]
+fn pool_and_depositor_info_aggregate_the_deposits() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit_with_ambassador(OTHER_USER, 11 * UNIT + FEE, b"deposit-2")
+        .assert_ok();
+
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        let info = sc.get_pool_info();
+        assert_eq!(info.pool_id, managed_buffer!(POOL_ID));
+        assert_eq!(info.owner, managed_address!(&address(OWNER)));
+        assert_eq!(info.status, PoolStatus::Open);
+        assert_eq!(info.soft_cap, managed_biguint!(50));
+        assert_eq!(info.hard_cap, managed_biguint!(100));
+        assert_eq!(info.start_date, START_DATE);
+        assert_eq!(info.end_date, END_DATE);
+        assert_eq!(
+            info.platform_fee_wallet,
+            managed_address!(&address(PLATFORM_FEE_WALLET))
+        );
+        assert_eq!(info.depositors_count, 2);
+        assert_eq!(info.ambassadors_count, 1);
+        assert_eq!(info.rounds_count, 0);
+        assert_eq!(info.currencies.len(), 3);
+        let usdc = info.currencies.get(0);
+        assert_eq!(usdc.token_identifier, managed_token_id_wrapped!(TOKEN));
+        assert_eq!(usdc.decimals, TOKEN_DECIMALS);
+        assert_eq!(usdc.total_amount, managed_biguint!(42 * UNIT + FEE));
+        assert_eq!(usdc.platform_fee, managed_biguint!(2 * FEE));
+        assert_eq!(usdc.group_fee, managed_biguint!(2 * FEE));
+        assert_eq!(usdc.ambassador_fee, managed_biguint!(FEE));
+        assert_eq!(info.currencies.get(1).total_amount, managed_biguint!(0));
+
+        let depositor = sc.get_depositor_info(managed_address!(&address(OTHER_USER)));
+        assert_eq!(
+            depositor.net_deposited_amount,
+            managed_biguint!(10 * UNIT) * 1_000_000_000_000u64
+        );
+        assert_eq!(depositor.deposits.len(), 1);
+        let deposit = depositor.deposits.get(0);
+        assert_eq!(deposit.deposited_amount, managed_biguint!(11 * UNIT + FEE));
+        assert_eq!(deposit.platform_fee, managed_biguint!(FEE));
+        assert_eq!(deposit.group_fee, managed_biguint!(FEE));
+        assert_eq!(deposit.ambassador_fee, managed_biguint!(FEE));
+        assert_eq!(depositor.ambassadors.len(), 1);
+        let ambassador = depositor.ambassadors.get(0);
+        assert_eq!(
+            ambassador.ambassador,
+            managed_address!(&address(AMBASSADOR))
+        );
+        assert_eq!(ambassador.amount, managed_biguint!(FEE));
+
+        let unknown = sc.get_depositor_info(managed_address!(&address(PARTNER)));
+        assert!(unknown.deposits.is_empty());
+        assert_eq!(unknown.net_deposited_amount, managed_biguint!(0));
+    });
+}
+
+#[test]
+fn depositors_and_ambassadors_are_paginated() {
+    let mut setup = RaisePoolSetup::new();
+    setup.set_timestamp(SALE_TIMESTAMP);
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+    setup
+        .deposit_with_ambassador(OTHER_USER, 11 * UNIT + FEE, b"deposit-2")
+        .assert_ok();
+
+    setup.world.whitebox_query(&setup.raise_pool, |sc| {
+        assert_eq!(sc.get_addresses_count(), 2);
+        assert_eq!(sc.get_ambassadors_count(), 1);
+
+        let page = |start: usize, count: usize| -> Vec<(Address, u64)> {
+            sc.get_addresses_page(start, count)
+                .into_iter()
+                .map(|entry| {
+                    let (address, payments) = entry.into_tuple();
+                    assert_eq!(payments.len(), 1);
+                    let amount = payments.get(0).amount.to_u64().unwrap();
+                    (address.to_address(), amount)
+                })
+                .collect()
+        };
+        assert_eq!(page(0, 1), vec![(address(USER), 31 * UNIT)]);
+        assert_eq!(page(1, 5), vec![(address(OTHER_USER), 11 * UNIT + FEE)]);
+        assert_eq!(page(0, 10).len(), 2);
+        assert!(page(2, 5).is_empty());
+        assert!(page(usize::MAX, usize::MAX).is_empty());
+
+        let ambassadors: Vec<_> = sc
+            .get_ambassadors_page(0, 10)
+            .into_iter()
+            .map(|entry| entry.into_tuple())
+            .collect();
+        assert_eq!(ambassadors.len(), 1);
+        let (ambassador, fees) = &ambassadors[0];
+        assert_eq!(*ambassador, managed_address!(&address(AMBASSADOR)));
+        assert_eq!(fees.get(0).amount, managed_biguint!(FEE));
+        assert!(sc.get_ambassadors_page(1, 10).is_empty());
+    });
+}
+
+#[test]
+fn validate_deposit_reports_failures_and_the_max_amount() {
+    let mut setup = RaisePoolSetup::new();
+    let validate = |setup: &mut RaisePoolSetup, caller: &str, token: &str, amount: u64| {
+        let mut validation = (Vec::new(), 0);
+        setup.world.whitebox_query(&setup.raise_pool, |sc| {
+            let result = sc.validate_deposit_view(
+                managed_address!(&address(caller)),
+                managed_token_id_wrapped!(token),
+                managed_biguint!(amount),
+                managed_biguint!(FEE),
+                managed_biguint!(FEE),
+                MultiValueEncoded::new(),
+            );
+            validation = (
+                result.failures.iter().collect::<Vec<DepositFailure>>(),
+                result.max_amount.to_u64().unwrap(),
+            );
+        });
+        validation
+    };
+
+    assert_eq!(
+        validate(&mut setup, USER, TOKEN, 31 * UNIT).0,
+        vec![DepositFailure::PoolNotOpenYet]
+    );
+
+    setup.set_timestamp(SALE_TIMESTAMP);
+    assert_eq!(
+        validate(&mut setup, USER, TOKEN, 31 * UNIT),
+        (vec![], 101 * UNIT)
+    );
+    setup
+        .deposit(USER, 31 * UNIT, b"deposit-1", SALE_TIMESTAMP)
+        .assert_ok();
+
+    // 30 of the hard cap of 100 are used: the max amount is 70 net plus the fees.
+    assert_eq!(
+        validate(&mut setup, OTHER_USER, TOKEN, 80 * UNIT),
+        (vec![DepositFailure::HardCapExceeded], 71 * UNIT)
+    );
+    assert_eq!(
+        validate(&mut setup, OTHER_USER, TOKEN, UNIT).0,
+        vec![DepositFailure::FeesExceedAmount]
+    );
+    assert_eq!(
+        validate(&mut setup, PARTNER, TOKEN, 31 * UNIT),
+        (vec![DepositFailure::WalletNotRegistered], 0)
+    );
+    assert_eq!(
+        validate(&mut setup, OTHER_USER, "ABC-123456", 31 * UNIT).0,
+        vec![DepositFailure::InvalidToken]
+    );
+
+    // The reported max amount is accepted by deposit.
+    setup
+        .deposit(OTHER_USER, 71 * UNIT, b"deposit-2", SALE_TIMESTAMP)
+        .assert_ok();
+    assert_eq!(
+        validate(&mut setup, OTHER_USER, TOKEN, 2 * UNIT),
+        (vec![DepositFailure::HardCapExceeded], 0)
+    );
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           49
+// Endpoints:                          121
 // Async Callback (empty):               1
-// Total number of exported functions:  52
+// Total number of exported functions: 124
 
 #![no_std]
 
@@ -21,14 +21,37 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deposit => deposit
+        depositWithAllocation => deposit_with_allocation
+        depositFor => deposit_for
+        depositMulti => deposit_multi
         refund => refund
+        claimRefund => claim_refund
         release => release
+        setFeeClaimMode => set_fee_claim_mode
+        claimFees => claim_fees
+        sweepUnclaimedFees => sweep_unclaimed_fees
         userRefund => user_refund
+        withdraw => withdraw
+        setWithdrawalPenaltySchedule => set_withdrawal_penalty_schedule
         adminRefund => admin_refund
+        cancelPool => cancel_pool
         setPlatformFeeWallet => set_platform_fee_wallet
         enableRaisePool => enable_raise_pool
         setTimestamps => set_timestamps
         setRefundEnabled => set_refund_enabled
+        setDepositPolicy => set_deposit_policy
+        addRound => add_round
+        updateRound => update_round
+        addToRoundWhitelist => add_to_round_whitelist
+        removeFromRoundWhitelist => remove_from_round_whitelist
+        setUseFactoryConfig => set_use_factory_config
+        rotateSigner => rotate_signer
+        addSigner => add_signer
+        removeSigner => remove_signer
+        setSignerThreshold => set_signer_threshold
+        setOversubscriptionEnabled => set_oversubscription_enabled
+        submitReleasePlan => submit_release_plan
+        clearReleasePlan => clear_release_plan
         getPoolId => pool_id
         getSoftCap => soft_cap
         getHardCap => hard_cap
@@ -45,10 +68,19 @@ multiversx_sc_wasm_adapter::endpoints! {
         getCurrencyDecimals => currency_decimals
         getWallatDatabaseAddress => wallet_database_address
         getSigner => signer
+        getFactoryAddress => factory_address
+        getUseFactoryConfig => use_factory_config
+        getPreviousSigner => previous_signer
+        getPreviousSignerExpiry => previous_signer_expiry
+        getSigners => signers
+        getSignerThreshold => signer_threshold
+        getConfigHash => config_hash
         getAddresses => addresses
         getRefundIndex => refund_index
         getDepositedCurrencies => deposited_currencies
         getDepositedAmount => deposited_amount
+        getDeposit => deposits
+        getDepositTranches => deposit_tranches
         getTotalAmount => total_amount
         getTotalAmountCurrency => total_amount_currency
         getAddressPlatformFee => address_platform_fee
@@ -65,10 +97,50 @@ multiversx_sc_wasm_adapter::endpoints! {
         getAmbassadorCurrencies => ambassador_currencies
         getReferralAmbassadorFee => referral_ambassador_fee
         getAddressToAmbassador => address_to_ambassadors
+        getAddressReferralAmbassadorFee => address_referral_ambassador_fee
+        getOversubscriptionEnabled => oversubscription_enabled
+        getDepositPolicy => deposit_policy
+        getWalletMaxAllocation => wallet_max_allocation
+        getWithdrawalPenaltySchedule => withdrawal_penalty_schedule
+        getRounds => rounds
+        getRoundWhitelist => round_whitelist
+        getRoundTotal => round_total
+        getRoundDepositedAmount => round_deposited_amount
+        getAllocationTotal => allocation_total
+        getAllocationIndex => allocation_index
+        getReleasePlan => release_plan
+        getReleasePlanHash => release_plan_hash
+        getReleasePlanFinalized => release_plan_finalized
         getOvercommitedIndex => overcommited_index
+        getPoolCancelled => pool_cancelled
+        getLastPoolStatus => last_pool_status
+        getRefundStarted => refund_started
+        getReleaseStarted => release_started
         getReleaseState => release_state
+        getFeeClaimMode => fee_claim_mode
+        getFeeClaimDeadline => fee_claim_deadline
+        getFeesSwept => fees_swept
         raisePoolEnabled => raise_pool_enabled
         getOwner => owner
+        getCurrentSigner => current_signer
+        getCurrentWalletDatabaseAddress => current_wallet_database_address
+        getCurrentPlatformFeeWallet => current_platform_fee_wallet
+        getPoolStatus => pool_status
+        getPoolInfo => get_pool_info
+        getDepositorInfo => get_depositor_info
+        getAddressesCount => get_addresses_count
+        getAmbassadorsCount => get_ambassadors_count
+        getAddressesPage => get_addresses_page
+        getAmbassadorsPage => get_ambassadors_page
+        getClaimableFees => get_claimable_fees
+        getWithdrawalPenalty => get_withdrawal_penalty
+        getActiveRound => get_active_round
+        getWalletAllocation => get_wallet_allocation
+        getRemainingAllocation => get_remaining_allocation
+        getAllocation => get_allocation
+        getExcessRefund => get_excess_refund
+        simulateRelease => simulate_release
+        validateDeposit => validate_deposit_view
     )
 }
 
@@ -26,14 +26,13 @@ import {
   INCORRECT_DECIMALS,
   CURRENCY3,
   TIMESTAMP_AFTER,
-  PAYMENT_NETWORK_ID,
 } from "./helpers.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
-  SIGNATURE_BEFORE,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
 let world: LSWorld;
@@ -77,6 +76,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNER WALLET
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U32(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -147,24 +147,11 @@ test("Deploy Pool with incorrect min deposit", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT_INCORRECT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        minDeposit: MIN_DEPOSIT_INCORRECT,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({ code: 10, message: "error signalled by smartcontract" });
 });
@@ -177,24 +164,11 @@ test("Deploy Pool with incorrect max deposit", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT_INCORRECT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        maxDeposit: MAX_DEPOSIT_INCORRECT,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({ code: 10, message: "error signalled by smartcontract" });
 });
@@ -206,24 +180,14 @@ test("Deploy Pool with invalid Signature", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DUMMY),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs(
+        {
+          ...POOL_CONFIG,
+          currencies: [CURRENCY1, CURRENCY2],
+        },
+        TIMESTAMP,
+        SIGNATURE_DUMMY,
+      ),
     })
     .assertFail({ code: 10, message: "invalid signature" });
 });
@@ -234,24 +198,13 @@ test("Deploy Pool with too much delay", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_BEFORE),
-        e.U64(TIMESTAMP_BEFORE),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs(
+        {
+          ...POOL_CONFIG,
+          currencies: [CURRENCY1, CURRENCY2],
+        },
+        TIMESTAMP_BEFORE,
+      ),
     })
     .assertFail({ code: 4, message: "Deploy took too long" });
 });
@@ -262,24 +215,11 @@ test("Deploy Pool with invalid Soft, Hard Cap pair", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP_INVALID),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        hardCap: HARD_CAP_INVALID,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({ code: 10, message: "error signalled by smartcontract" });
 });
@@ -290,24 +230,11 @@ test("Deploy Pool with invalid Min, Max Deposit pair", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT_INVALID),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        maxDeposit: MAX_DEPOSIT_INVALID,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({ code: 10, message: "error signalled by smartcontract" });
 });
@@ -318,24 +245,11 @@ test("Deploy Pool with invalid Start, End Date pair", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE_INVALID),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        endDate: END_DATE_INVALID,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({ code: 10, message: "error signalled by smartcontract" });
 });
@@ -365,24 +279,10 @@ test("Deploy Pool with not whitelisted currency", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(PAYMENT_NETWORK_ID),
-        e.Str(CURRENCY3),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        currencies: [CURRENCY3],
+      }),
     })
     .assertFail({
       code: 4,
@@ -395,24 +295,10 @@ test("Deploy Pool with same pool id twice", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   await deployer
@@ -420,24 +306,10 @@ test("Deploy Pool with same pool id twice", async () => {
       callee: factoryContract,
       gasLimit: 50_000_000,
       funcName: "deployRaisePool",
-      funcArgs: [
-        e.Str(POOL_ID),
-        e.U64(SOFT_CAP),
-        e.U64(HARD_CAP),
-        e.U64(MIN_DEPOSIT),
-        e.U64(MAX_DEPOSIT),
-        e.U64(DEPOSIT_INCREMENTS),
-        e.U64(START_DATE),
-        e.U64(END_DATE),
-        e.U64(REFUND_ENABLED),
-        e.U64(END_DATE),
-        e.Addr(deployer),
-        e.Addr(deployer),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
-        e.U64(TIMESTAMP),
-        e.Str(CURRENCY1),
-        e.Str(CURRENCY2),
-      ],
+      funcArgs: deployRaisePoolArgs({
+        ...POOL_CONFIG,
+        currencies: [CURRENCY1, CURRENCY2],
+      }),
     })
     .assertFail({
       code: 4,
@@ -450,25 +322,10 @@ test("Deploy Pool", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   assertAccount(await factoryContract.getAccount(), {
@@ -1,5 +1,6 @@
 import { test, beforeEach, afterEach } from "vitest";
 import { e } from "xsuite";
+import { BooleanValue } from "@multiversx/sdk-core";
 
 import {
   assertAccount,
@@ -51,13 +52,17 @@ import {
   HIGH_HARD_CAP,
   AFTER_DEPOSIT_TIMESTAMP,
   DEPOSIT_ID,
-  PAYMENT_NETWORK_ID,
+  DEPOSIT_ID2,
+  DEPOSIT_ID3,
 } from "./helpers.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  signOwnerCall,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
+  signDeposit,
 } from "./signatures/deployer.ts";
 
 import {
@@ -68,12 +73,14 @@ import {
   SIGNATURE_BOB_WALLET,
   SIGNATURE_BOB_AFTER_DEPOSIT,
   SIGNATURE_DATA_BOB_CUSTOM,
+  BOB_DEPOSIT,
 } from "./signatures/bob.ts";
 
 import {
   carolAddress,
   SIGNATURE_CAROL_WALLET,
   SIGNATURE_CAROL_WITHOUT_AMBASSADOR,
+  CAROL_DEPOSIT,
 } from "./signatures/carol.ts";
 
 import {
@@ -127,6 +134,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNER WALLET
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U32(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -162,24 +170,10 @@ test("Deposit with invalid signature", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -230,25 +224,7 @@ test("Deposit with invalid token", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -292,7 +268,7 @@ test("Deposit with invalid token", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+        e.TopBuffer(signDeposit({ ...BOB_DEPOSIT, token: DUMMY_TOKEN })),
         e.U(PLATFORM_FEE1),
         e.U(GROUP_FEE1),
         e.Str(DEPOSIT_ID),
@@ -309,25 +285,10 @@ test("Deposit while deposits not open yet", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -372,7 +333,7 @@ test("Deposit while deposits not open yet", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+        e.TopBuffer(signDeposit({ ...BOB_DEPOSIT, amount: 100_000 })),
         e.U(PLATFORM_FEE1),
         e.U(GROUP_FEE1),
         e.Str(DEPOSIT_ID),
@@ -389,25 +350,10 @@ test("Deposit while deposits closed", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -464,25 +410,10 @@ test("Deposit with too much delay", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -544,25 +475,10 @@ test("Deposit while deposits closed", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -622,25 +538,10 @@ test("Deposit amount too low", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -661,10 +562,11 @@ test("Deposit amount too low", async () => {
     gasLimit: 50_000_000,
     funcName: "enableRaisePool",
     funcArgs: [
-      e.U64(TIMESTAMP),
-      e.Str(POOL_ID),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
       e.Bool(true),
+      e.U64(TIMESTAMP),
+      e.TopBuffer(
+        signOwnerCall(TIMESTAMP, "enableRaisePool", [new BooleanValue(true)]),
+      ),
     ],
   });
 
@@ -697,7 +599,9 @@ test("Deposit amount too low", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+        e.TopBuffer(
+          signDeposit({ ...BOB_DEPOSIT, amount: CURRENCY1_DEPOSIT_TOO_LOW }),
+        ),
         e.U(PLATFORM_FEE1),
         e.U(GROUP_FEE1),
         e.Str(DEPOSIT_ID),
@@ -715,25 +619,10 @@ test("Deposit amount too high", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -777,7 +666,12 @@ test("Deposit amount too high", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+        e.TopBuffer(
+          signDeposit({
+            ...BOB_DEPOSIT,
+            amount: CURRENCY1_DEPOSIT_AMOUNT * BigInt(3),
+          }),
+        ),
         e.U(PLATFORM_FEE1),
         e.U(GROUP_FEE1),
         e.Str(DEPOSIT_ID),
@@ -789,32 +683,86 @@ test("Deposit amount too high", async () => {
     .assertFail({ code: 4, message: "Payment amount too high" });
 });
 
-test("Deposit but hard cap threshold would be exceeded", async () => {
+test("Deposit amount above the signed max allocation", async () => {
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(LOW_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY3),
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
+  });
+
+  const raisePoolAddressResult = await deployer.query({
+    callee: factoryContract,
+    funcName: "getPoolIdToAddress",
+    funcArgs: [e.Str(POOL_ID)],
+  });
+
+  const raisePoolAddress = raisePoolAddressResult.returnData[0];
+
+  const raisePoolContract = new LSContract({
+    address: raisePoolAddress,
+    world,
+  });
+
+  await world.setCurrentBlockInfo({
+    timestamp: DEPOSIT_TIMESTAMP,
+  });
+
+  bob = await world.createWallet({
+    address: bobAddress,
+    balance: 100_000,
+    kvs: [
+      e.kvs.Esdts([
+        { id: CURRENCY1, amount: CURRENCY1_DEPOSIT_AMOUNT * BigInt(100) },
+      ]),
     ],
   });
 
+  await bob.callContract({
+    callee: walletDababaseContract,
+    gasLimit: 50_000_000,
+    funcName: "registerWallet",
+    funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_BOB_WALLET)],
+  });
+
+  await bob
+    .callContract({
+      callee: raisePoolContract,
+      gasLimit: 50_000_000,
+      funcName: "depositWithAllocation",
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(
+          signDeposit({ ...BOB_DEPOSIT, maxAllocation: MIN_DEPOSIT }),
+        ),
+        e.U(PLATFORM_FEE1),
+        e.U(GROUP_FEE1),
+        e.Str(DEPOSIT_ID),
+        e.U(MIN_DEPOSIT),
+        e.U(AMBASSADOR_FEE1),
+        e.Addr(deployer),
+      ],
+      esdts: [{ id: CURRENCY1, amount: CURRENCY1_DEPOSIT_AMOUNT }],
+    })
+    .assertFail({ code: 4, message: "Payment amount too high" });
+});
+
+test("Deposit but hard cap threshold would be exceeded", async () => {
+  await deployer.callContract({
+    callee: factoryContract,
+    gasLimit: 50_000_000,
+    funcName: "deployRaisePool",
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: LOW_HARD_CAP,
+      currencies: [CURRENCY1, CURRENCY3],
+    }),
+  });
+
   const raisePoolAddressResult = await deployer.query({
     callee: factoryContract,
     funcName: "getPoolIdToAddress",
@@ -851,7 +799,9 @@ test("Deposit but hard cap threshold would be exceeded", async () => {
     funcName: "deposit",
     funcArgs: [
       e.U64(TIMESTAMP),
-      e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+      e.TopBuffer(
+        signDeposit({ ...BOB_DEPOSIT, amount: CURRENCY1_DEPOSIT_MAX }),
+      ),
       e.U(PLATFORM_FEE1),
       e.U(GROUP_FEE1),
       e.Str(DEPOSIT_ID),
@@ -886,10 +836,12 @@ test("Deposit but hard cap threshold would be exceeded", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_CAROL_WITHOUT_AMBASSADOR),
+        e.TopBuffer(
+          signDeposit({ ...CAROL_DEPOSIT, amount: CURRENCY3_DEPOSIT_MAX }),
+        ),
         e.U(PLATFORM_FEE3),
         e.U(GROUP_FEE3),
-        e.Str(DEPOSIT_ID),
+        e.Str(DEPOSIT_ID3),
       ],
       esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_MAX }],
     })
@@ -902,25 +854,10 @@ test("Deposit with incorect deposit increment", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -941,10 +878,11 @@ test("Deposit with incorect deposit increment", async () => {
     gasLimit: 50_000_000,
     funcName: "enableRaisePool",
     funcArgs: [
-      e.U64(TIMESTAMP),
-      e.Str(POOL_ID),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
       e.Bool(true),
+      e.U64(TIMESTAMP),
+      e.TopBuffer(
+        signOwnerCall(TIMESTAMP, "enableRaisePool", [new BooleanValue(true)]),
+      ),
     ],
   });
 
@@ -972,7 +910,12 @@ test("Deposit with incorect deposit increment", async () => {
       funcName: "deposit",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR),
+        e.TopBuffer(
+          signDeposit({
+            ...BOB_DEPOSIT,
+            amount: CURRENCY1_DEPOSIT_INCORECT_AMOUNT,
+          }),
+        ),
         e.U(PLATFORM_FEE1),
         e.U(GROUP_FEE1),
         e.Str(DEPOSIT_ID),
@@ -993,25 +936,10 @@ test("Deposit Currency1 with Bob", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1140,25 +1068,10 @@ test("Deposit Currency1, Currency2 with Bob", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1221,7 +1134,7 @@ test("Deposit Currency1, Currency2 with Bob", async () => {
       e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR2),
       e.U(PLATFORM_FEE2),
       e.U(GROUP_FEE2),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID2),
       e.U(AMBASSADOR_FEE2),
       e.Addr(deployer),
     ],
@@ -1353,26 +1266,7 @@ test("Deposit Currency1, Currency2 with Bob, Currency3 with Carol", async () =>
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1435,7 +1329,7 @@ test("Deposit Currency1, Currency2 with Bob, Currency3 with Carol", async () =>
       e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR2),
       e.U(PLATFORM_FEE2),
       e.U(GROUP_FEE2),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID2),
       e.U(AMBASSADOR_FEE2),
       e.Addr(deployer),
     ],
@@ -1464,7 +1358,7 @@ test("Deposit Currency1, Currency2 with Bob, Currency3 with Carol", async () =>
       e.TopBuffer(SIGNATURE_CAROL_WITHOUT_AMBASSADOR),
       e.U(PLATFORM_FEE3),
       e.U(GROUP_FEE3),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   });
@@ -1625,26 +1519,10 @@ test("Deposit automatically with random parameters", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1722,6 +1600,7 @@ test("Deposit automatically with random parameters", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1730,7 +1609,12 @@ test("Deposit automatically with random parameters", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(ambassadorBool, depositAmount);
+    } = generateDataAndSignature(
+      ambassadorBool,
+      currency,
+      depositAmount,
+      depositId,
+    );
 
     const depositAmountDenominated =
       BigInt(depositAmount) * BigInt(10 ** (18 - decimals));
@@ -1762,7 +1646,7 @@ test("Deposit automatically with random parameters", async () => {
           e.TopBuffer(depositSignature),
           e.U(platformFee),
           e.U(groupFee),
-          e.Str(DEPOSIT_ID),
+          e.Str(depositId),
           e.U(ambassadorFee),
           e.Addr(ambassadorAddress),
         ],
@@ -1778,7 +1662,7 @@ test("Deposit automatically with random parameters", async () => {
           e.TopBuffer(depositSignature),
           e.U(platformFee),
           e.U(groupFee),
-          e.Str(DEPOSIT_ID),
+          e.Str(depositId),
         ],
         esdts: [{ id: currency, amount: depositAmount }],
       });
@@ -1936,26 +1820,10 @@ test("Deposit automatically with deployer as ambassador", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -2033,6 +1901,7 @@ test("Deposit automatically with deployer as ambassador", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -2040,7 +1909,11 @@ test("Deposit automatically with deployer as ambassador", async () => {
       platformFee,
       groupFee,
       ambassadorFee,
-    } = generateDataAndSignatureDeployerAmbassador(depositAmount);
+    } = generateDataAndSignatureDeployerAmbassador(
+      currency,
+      depositAmount,
+      depositId,
+    );
 
     const depositAmountDenominated =
       BigInt(depositAmount) * BigInt(10 ** (18 - decimals));
@@ -2071,7 +1944,7 @@ test("Deposit automatically with deployer as ambassador", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(deployer),
       ],
@@ -2241,26 +2114,10 @@ test("Deposit automatically with random user ambassador and deployer address as
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -2339,6 +2196,7 @@ test("Deposit automatically with random user ambassador and deployer address as
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -2348,7 +2206,11 @@ test("Deposit automatically with random user ambassador and deployer address as
       ambassadorFee1,
       ambassadorAddress1,
       ambassadorFee2,
-    } = generateDataAndSignature2Ambassadors(depositAmount);
+    } = generateDataAndSignature2Ambassadors(
+      currency,
+      depositAmount,
+      depositId,
+    );
 
     const depositAmountDenominated =
       BigInt(depositAmount) * BigInt(10 ** (18 - decimals));
@@ -2381,7 +2243,7 @@ test("Deposit automatically with random user ambassador and deployer address as
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee1),
         e.Addr(ambassadorAddress1),
         e.U(ambassadorFee2),
@@ -22,10 +22,6 @@ import {
   DECIMALS3,
   MIN_DEPOSIT,
   MAX_DEPOSIT,
-  DEPOSIT_INCREMENTS,
-  START_DATE,
-  END_DATE,
-  REFUND_ENABLED,
   PLATFORM_FEE1,
   GROUP_FEE1,
   AMBASSADOR_FEE1,
@@ -47,13 +43,13 @@ import {
   HIGH_HARD_CAP,
   AFTER_DEPOSIT_TIMESTAMP,
   DEPOSIT_ID,
-  PAYMENT_NETWORK_ID,
 } from "./helpers.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
 import {
@@ -121,6 +117,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNER WALLET
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U32(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -156,25 +153,14 @@ test("Deposit Currency1 with Bob", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(4000000),
-      e.U64(4000000),
-      e.U64(1000000),
-      e.U64(4000000),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: 4000000,
+      hardCap: 4000000,
+      minDeposit: 1000000,
+      maxDeposit: 4000000,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
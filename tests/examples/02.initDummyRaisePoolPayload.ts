@@ -52,6 +52,7 @@ function getTransactionPayload() {
     new AddressValue(new Address(deployerAddressBech32)),
     new AddressValue(new Address(signerAddressBech32)),
     new AddressValue(new Address(WALLET_DATABASE_CONTRACT_ADDRESS)),
+    new BytesValue(Buffer.alloc(32)),
     VariadicValue.fromItems(
       CompositeValue.fromItems(
         new TokenIdentifierValue(CURRENCY1),
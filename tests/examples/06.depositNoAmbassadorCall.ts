@@ -1,6 +1,11 @@
 import { ContractLoader } from "./ContractLoader";
 import { getTransactionPayloadNoAmbassador } from "./06.depositNoAmbassadorPayload";
-import { privateKeyBob, bobAddressBech32 } from "./signedData";
+import {
+  privateKeyBob,
+  bobAddressBech32,
+  AMOUNT,
+  DEPOSIT_TOKEN,
+} from "./signedData";
 import { Address, TokenTransfer, Account } from "@multiversx/sdk-core";
 import { ApiNetworkProvider } from "@multiversx/sdk-network-providers";
 const apiNetworkProvider = new ApiNetworkProvider(
@@ -34,7 +39,7 @@ async function callDepositNoAmbassador() {
     .withSender(new Address(bobAddressBech32))
     .withNonce(nonce)
     .withSingleESDTTransfer(
-      TokenTransfer.fungibleFromBigInteger("USDC-350c4e", 100000000),
+      TokenTransfer.fungibleFromBigInteger(DEPOSIT_TOKEN, AMOUNT),
     )
     .buildTransaction();
 
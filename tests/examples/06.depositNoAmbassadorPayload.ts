@@ -10,12 +10,14 @@ import {
   VariadicValue,
   TokenIdentifierType,
   VariadicType,
+  StringValue,
 } from "@multiversx/sdk-core";
 
 import {
   TIMESTAMP,
   PLATFORM_FEE,
   GROUP_FEE,
+  DEPOSIT_ID_NO_AMBASSADOR,
   SIGNATURE_DEPLOY_BOB_WALLET_NO_AMBASSADOR,
 } from "./signedData";
 
@@ -25,6 +27,7 @@ function getTransactionPayloadNoAmbassador() {
     BytesValue.fromHex(SIGNATURE_DEPLOY_BOB_WALLET_NO_AMBASSADOR),
     new BigUIntValue(PLATFORM_FEE),
     new BigUIntValue(GROUP_FEE),
+    StringValue.fromUTF8(DEPOSIT_ID_NO_AMBASSADOR),
   ];
   return transactionPayload;
 }
@@ -1,6 +1,10 @@
 import { ContractLoader } from "./ContractLoader";
 import { getTransactionPayloadWithAmbassador } from "./07.depositWithAmbassadorPayload";
-import { privateKeyBob, bobAddressBech32 } from "./signedData";
+import {
+  privateKeyBob,
+  bobAddressBech32,
+  DEPOSIT_TOKEN,
+} from "./signedData";
 import { Address, TokenTransfer, Account } from "@multiversx/sdk-core";
 import { ApiNetworkProvider } from "@multiversx/sdk-network-providers";
 const apiNetworkProvider = new ApiNetworkProvider(
@@ -35,7 +39,7 @@ async function callDepositWithAmbassador() {
     .withSender(new Address(bobAddressBech32))
     .withNonce(nonce)
     .withSingleESDTTransfer(
-      TokenTransfer.fungibleFromBigInteger("USDC-350c4e", AMOUNT),
+      TokenTransfer.fungibleFromBigInteger(DEPOSIT_TOKEN, AMOUNT),
     )
     .buildTransaction();
 
@@ -10,6 +10,7 @@ import {
   VariadicValue,
   TokenIdentifierType,
   VariadicType,
+  StringValue,
   OptionValue,
   CompositeValue,
   CompositeType,
@@ -23,6 +24,7 @@ import {
   AMBASSADOR_FEE,
   AMOUNT,
   deployerAddressBech32,
+  DEPOSIT_ID_WITH_AMBASSADOR,
   SIGNATURE_DEPLOY_BOB_WALLET_WITH_AMBASSADOR,
 } from "./signedData";
 
@@ -32,6 +34,7 @@ function getTransactionPayloadWithAmbassador() {
     BytesValue.fromHex(SIGNATURE_DEPLOY_BOB_WALLET_WITH_AMBASSADOR),
     new BigUIntValue(PLATFORM_FEE),
     new BigUIntValue(GROUP_FEE),
+    StringValue.fromUTF8(DEPOSIT_ID_WITH_AMBASSADOR),
     new OptionalValue(
       new CompositeType(),
       CompositeValue.fromItems(
@@ -5,8 +5,12 @@ import {
   U32Value,
   U64Value,
   BigUIntValue,
+  BooleanValue,
+  StringValue,
+  OptionValue,
   BinaryCodec,
 } from "@multiversx/sdk-core";
+import { keccak_256 } from "@noble/hashes/sha3";
 const codec = new BinaryCodec();
 
 // LOAD WALLETS
@@ -62,13 +66,53 @@ export const PLATFORM_FEE = 100;
 export const GROUP_FEE = 200;
 export const AMBASSADOR_FEE = 300;
 export const AMOUNT = 100000000;
+export const DEPOSIT_TOKEN = "USDC-350c4e";
+export const DEPOSIT_ID_NO_AMBASSADOR = "ID0";
+export const DEPOSIT_ID_WITH_AMBASSADOR = "ID1";
 
-// SIGN DATA_DEPLOYER => DATA_DEPLOYER_SIGNED = signed(timestamp + pool_id + deployer_address)
+// RAISE POOL CONFIGURATION, deployed by 04.deployRaisePool
+export const SOFT_CAP = 10000;
+export const HARD_CAP = 50000;
+export const MIN_DEPOSIT = 100;
+export const MAX_DEPOSIT = 5000;
+export const DEPOSIT_INCREMENTS = 50;
+export const START_DATE = 1725534643;
+export const END_DATE = 1818110409;
+export const REFUND_ENABLED = true;
+export const REFUND_DEADLINE = 1818110409;
+export const PAYMENT_NETWORK_ID = "MULTIVERSX";
+export const CURRENCIES = ["USDC-350c4e", "USDT-58d5d0"];
+
+// CONFIG_HASH = keccak256(pool_id + soft_cap + hard_cap + min_deposit + max_deposit + deposit_increments + start_date + end_date + refund_enabled + refund_deadline + platform_fee_wallet + group_fee_wallet + payment_network_id + currencies_count + currencies), every field nested-encoded.
+//
+const CONFIG_DATA = Buffer.concat([
+  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+  codec.encodeNested(new BigUIntValue(SOFT_CAP)),
+  codec.encodeNested(new BigUIntValue(HARD_CAP)),
+  codec.encodeNested(new BigUIntValue(MIN_DEPOSIT)),
+  codec.encodeNested(new BigUIntValue(MAX_DEPOSIT)),
+  codec.encodeNested(new BigUIntValue(DEPOSIT_INCREMENTS)),
+  codec.encodeNested(new U64Value(START_DATE)),
+  codec.encodeNested(new U64Value(END_DATE)),
+  codec.encodeNested(new BooleanValue(REFUND_ENABLED)),
+  codec.encodeNested(new U64Value(REFUND_DEADLINE)),
+  deployerAddress, // PLATFORM FEE WALLET
+  deployerAddress, // GROUP FEE WALLET
+  codec.encodeNested(StringValue.fromUTF8(PAYMENT_NETWORK_ID)),
+  codec.encodeNested(new U32Value(CURRENCIES.length)),
+  ...CURRENCIES.map((currency) =>
+    codec.encodeNested(StringValue.fromUTF8(currency)),
+  ),
+]);
+const CONFIG_HASH = Buffer.from(keccak_256(CONFIG_DATA));
+
+// SIGN DATA_DEPLOYER => DATA_DEPLOYER_SIGNED = signed(timestamp + pool_id + deployer_address + config_hash)
 //
 const DATA_DEPLOYER = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
   codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
   deployerAddress,
+  CONFIG_HASH,
 ]);
 export const DATA_DEPLOYER_SIGNED = privateKeySigner
   .sign(DATA_DEPLOYER)
@@ -85,28 +129,38 @@ export const SIGNATURE_REGISTER_BOB_WALLET = privateKeySigner
   .toString("hex");
 
 // SIGN DEPLOY BOB WALLET NO AMBASSADOR =>
-// SIGNATURE_DEPLOY_BOB_WALLET_NO_AMBASSADOR = signed(timestamp + pool_id + deployer_address + platform_fee_percentage + group_fee_percentage).
+// SIGNATURE_DEPLOY_BOB_WALLET_NO_AMBASSADOR = signed(timestamp + pool_id + user_address + "deposit" + platform_fee_percentage + group_fee_percentage + token + amount + deposit_id + max_allocation).
 //
 var DATA_DEPLOY_BOB_WALLET_NO_AMBASSADOR = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
   codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
   bobAddress,
+  codec.encodeNested(StringValue.fromUTF8("deposit")),
   codec.encodeNested(new BigUIntValue(PLATFORM_FEE)),
   codec.encodeNested(new BigUIntValue(GROUP_FEE)),
+  codec.encodeNested(StringValue.fromUTF8(DEPOSIT_TOKEN)),
+  codec.encodeNested(new BigUIntValue(AMOUNT)),
+  codec.encodeNested(StringValue.fromUTF8(DEPOSIT_ID_NO_AMBASSADOR)),
+  codec.encodeNested(OptionValue.newMissing()),
 ]);
 export const SIGNATURE_DEPLOY_BOB_WALLET_NO_AMBASSADOR = privateKeySigner
   .sign(DATA_DEPLOY_BOB_WALLET_NO_AMBASSADOR)
   .toString("hex");
 
 // SIGN DEPLOY BOB WALLET WITH AMBASSADOR =>
-// SIGNATURE_DEPLOY_BOB_WALLET_WITH_AMBASSADOR = signed(timestamp + pool_id + deployer_address + platform_fee_percentage + group_fee_percentage + ambassador_fee + ambassador_address).
+// SIGNATURE_DEPLOY_BOB_WALLET_WITH_AMBASSADOR = signed(timestamp + pool_id + user_address + "deposit" + platform_fee_percentage + group_fee_percentage + token + amount + deposit_id + max_allocation + ambassador_fee + ambassador_address).
 //
 var DATA_DEPLOY_BOB_WALLET_WITH_AMBASSADOR = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
   codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
   bobAddress,
+  codec.encodeNested(StringValue.fromUTF8("deposit")),
   codec.encodeNested(new BigUIntValue(PLATFORM_FEE)),
   codec.encodeNested(new BigUIntValue(GROUP_FEE)),
+  codec.encodeNested(StringValue.fromUTF8(DEPOSIT_TOKEN)),
+  codec.encodeNested(new BigUIntValue(AMOUNT)),
+  codec.encodeNested(StringValue.fromUTF8(DEPOSIT_ID_WITH_AMBASSADOR)),
+  codec.encodeNested(OptionValue.newMissing()),
   codec.encodeNested(new BigUIntValue(AMBASSADOR_FEE)),
   deployerAddress,
 ]);
@@ -1,17 +1,17 @@
 import { UserSecretKey } from "@multiversx/sdk-wallet/out";
 import { Mnemonic } from "@multiversx/sdk-wallet";
-import {
-  BinaryCodec,
-  U64Value,
-  BigUIntValue,
-  StringValue,
-} from "@multiversx/sdk-core";
+import { BinaryCodec, U64Value } from "@multiversx/sdk-core";
 
 const MAX_PERCENTAGE = BigInt(10000);
 
-import { privateKeyDeployer, deployerAddress } from "./signatures/deployer";
+import {
+  privateKeyDeployer,
+  deployerAddress,
+  signDeposit,
+  AmbassadorFee,
+} from "./signatures/deployer";
 
-import { TIMESTAMP, POOL_ID } from "./helpers";
+import { TIMESTAMP } from "./helpers";
 
 const codec = new BinaryCodec();
 
@@ -33,7 +33,7 @@ export function getRandomDeposit(
   return BigInt(randomMultiple * increment * 10 ** decimals);
 }
 
-function generateAddress(): Buffer {
+export function generateAddress(): Buffer {
   const words = Mnemonic.generate().getWords();
   const mnemonic = words.join(" ");
   const derivedKey = Mnemonic.fromString(mnemonic).deriveKey(0);
@@ -44,7 +44,9 @@ function generateAddress(): Buffer {
 
 export function generateDataAndSignature(
   ambassadorBool: number,
+  currency: string,
   depositAmount: bigint,
+  depositId: string,
 ): {
   address: Buffer;
   whitelistSignature: Buffer;
@@ -65,27 +67,25 @@ export function generateDataAndSignature(
     address,
   ]);
 
-  var deploy_data = Buffer.concat([
-    codec.encodeNested(new U64Value(TIMESTAMP)),
-    codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-    address,
-    codec.encodeNested(new BigUIntValue(platformFee)),
-    codec.encodeNested(new BigUIntValue(groupFee)),
-  ]);
   var ambassadorFee = BigInt(0);
   var ambassadorAddress = new Uint8Array(0);
+  var ambassadors: AmbassadorFee[] = [];
   if (ambassadorBool == 1) {
     ambassadorFee =
       (BigInt(getRandomInt(201, 300)) * depositAmount) / MAX_PERCENTAGE;
     ambassadorAddress = generateAddress();
-    deploy_data = Buffer.concat([
-      deploy_data,
-      codec.encodeNested(new BigUIntValue(ambassadorFee)),
-      ambassadorAddress,
-    ]);
+    ambassadors = [[ambassadorFee, ambassadorAddress]];
   }
   const whitelistSignature = privateKeyDeployer.sign(whitelist_data);
-  const depositSignature = privateKeyDeployer.sign(deploy_data);
+  const depositSignature = signDeposit({
+    caller: address,
+    platformFee,
+    groupFee,
+    token: currency,
+    amount: depositAmount,
+    depositId,
+    ambassadors,
+  });
 
   return {
     address,
@@ -99,7 +99,9 @@ export function generateDataAndSignature(
 }
 
 export function generateDataAndSignatureDeployerAmbassador(
+  currency: string,
   depositAmount: bigint,
+  depositId: string,
 ): {
   address: Buffer;
   whitelistSignature: Buffer;
@@ -121,18 +123,16 @@ export function generateDataAndSignatureDeployerAmbassador(
     address,
   ]);
 
-  var deploy_data = Buffer.concat([
-    codec.encodeNested(new U64Value(TIMESTAMP)),
-    codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-    address,
-    codec.encodeNested(new BigUIntValue(platformFee)),
-    codec.encodeNested(new BigUIntValue(groupFee)),
-    codec.encodeNested(new BigUIntValue(ambassadorFee)),
-    deployerAddress,
-  ]);
-
   const whitelistSignature = privateKeyDeployer.sign(whitelist_data);
-  const depositSignature = privateKeyDeployer.sign(deploy_data);
+  const depositSignature = signDeposit({
+    caller: address,
+    platformFee,
+    groupFee,
+    token: currency,
+    amount: depositAmount,
+    depositId,
+    ambassadors: [[ambassadorFee, deployerAddress]],
+  });
 
   return {
     address,
@@ -144,7 +144,11 @@ export function generateDataAndSignatureDeployerAmbassador(
   };
 }
 
-export function generateDataAndSignature2Ambassadors(depositAmount: bigint): {
+export function generateDataAndSignature2Ambassadors(
+  currency: string,
+  depositAmount: bigint,
+  depositId: string,
+): {
   address: Buffer;
   whitelistSignature: Buffer;
   depositSignature: Buffer;
@@ -171,19 +175,19 @@ export function generateDataAndSignature2Ambassadors(depositAmount: bigint): {
   const ambassadorFee2 =
     (BigInt(getRandomInt(201, 300)) * depositAmount) / MAX_PERCENTAGE;
 
-  var deploy_data = Buffer.concat([
-    codec.encodeNested(new U64Value(TIMESTAMP)),
-    codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-    address,
-    codec.encodeNested(new BigUIntValue(platformFee)),
-    codec.encodeNested(new BigUIntValue(groupFee)),
-    codec.encodeNested(new BigUIntValue(ambassadorFee1)),
-    ambassadorAddress1,
-    codec.encodeNested(new BigUIntValue(ambassadorFee2)),
-    deployerAddress,
-  ]);
   const whitelistSignature = privateKeyDeployer.sign(whitelist_data);
-  const depositSignature = privateKeyDeployer.sign(deploy_data);
+  const depositSignature = signDeposit({
+    caller: address,
+    platformFee,
+    groupFee,
+    token: currency,
+    amount: depositAmount,
+    depositId,
+    ambassadors: [
+      [ambassadorFee1, ambassadorAddress1],
+      [ambassadorFee2, deployerAddress],
+    ],
+  });
 
   return {
     address,
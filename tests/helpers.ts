@@ -58,3 +58,5 @@ export const BATCH_ID = 0;
 export const INCORRECT_BATCH_ID = 1;
 export const PAYMENT_NETWORK_ID = "PAYMENT_NETWORK_ID";
 export const DEPOSIT_ID = "ID0";
+export const DEPOSIT_ID2 = "ID1";
+export const DEPOSIT_ID3 = "ID2";
@@ -1,6 +1,7 @@
 import { test, beforeEach, afterEach } from "vitest";
 import { assertAccount, LSWorld, LSWallet, LSContract } from "xsuite";
 import { e } from "xsuite";
+import { Address, AddressValue, U64Value } from "@multiversx/sdk-core";
 import {
   POOL_ID,
   TIMESTAMP,
@@ -26,19 +27,20 @@ import {
   INCORRECT_DECIMALS,
   CURRENCY3,
   TIMESTAMP_AFTER,
-  PAYMENT_NETWORK_ID,
   DEPOSIT_TIMESTAMP,
 } from "./helpers.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
-  SIGNATURE_BEFORE,
+  signOwnerCall,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
+import { bobAddress } from "./signatures/bob.ts";
+
 let world: LSWorld;
-let alice: LSWallet;
 let deployer: LSWallet;
 let factoryContract: LSContract;
 let raisePoolDummyContract: LSContract;
@@ -50,7 +52,6 @@ beforeEach(async () => {
     timestamp: TIMESTAMP,
   });
   deployer = await world.createWallet({ address: deployerAddress });
-  alice = await world.createWallet();
 
   ({ contract: walletDababaseContract } = await deployer.deployContract({
     code: "file:wallet-database/output/wallet-database.wasm",
@@ -78,6 +79,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNATURE DEPLOYER
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U64(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -111,25 +113,11 @@ test("Change to wrong refund date", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(122), // END DATE
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      refundDeadline: 122,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -152,7 +140,13 @@ test("Change to wrong refund date", async () => {
       funcName: "setTimestamps",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
+        e.TopBuffer(
+          signOwnerCall(TIMESTAMP, "setTimestamps", [
+            new U64Value(START_DATE),
+            new U64Value(END_DATE),
+            new U64Value(TIMESTAMP_BEFORE),
+          ]),
+        ),
         e.U64(START_DATE),
         e.U64(END_DATE),
         e.U64(TIMESTAMP_BEFORE),
@@ -166,25 +160,11 @@ test("Change to end date before start date", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(122), // END DATE
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      refundDeadline: 122,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -207,7 +187,13 @@ test("Change to end date before start date", async () => {
       funcName: "setTimestamps",
       funcArgs: [
         e.U64(TIMESTAMP),
-        e.TopBuffer(SIGNATURE_DEPLOYER),
+        e.TopBuffer(
+          signOwnerCall(TIMESTAMP, "setTimestamps", [
+            new U64Value(END_DATE),
+            new U64Value(START_DATE),
+            new U64Value(TIMESTAMP),
+          ]),
+        ),
         e.U64(END_DATE),
         e.U64(START_DATE),
         e.U64(TIMESTAMP),
@@ -221,25 +207,11 @@ test("Change to wrong refund date", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(122), // END DATE
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      refundDeadline: 122,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -261,7 +233,13 @@ test("Change to wrong refund date", async () => {
     funcName: "setTimestamps",
     funcArgs: [
       e.U64(TIMESTAMP),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
+      e.TopBuffer(
+        signOwnerCall(TIMESTAMP, "setTimestamps", [
+          new U64Value(START_DATE),
+          new U64Value(END_DATE),
+          new U64Value(DEPOSIT_TIMESTAMP),
+        ]),
+      ),
       e.U64(START_DATE),
       e.U64(END_DATE),
       e.U64(DEPOSIT_TIMESTAMP),
@@ -274,25 +252,10 @@ test("Change platform fee wallet", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      currencies: [CURRENCY1, CURRENCY2],
+    }),
   });
 
   assertAccount(await factoryContract.getAccount(), {
@@ -343,8 +306,12 @@ test("Change platform fee wallet", async () => {
     funcName: "setPlatformFeeWallet",
     funcArgs: [
       e.U64(TIMESTAMP),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.Addr(alice),
+      e.TopBuffer(
+        signOwnerCall(TIMESTAMP, "setPlatformFeeWallet", [
+          new AddressValue(new Address(bobAddress)),
+        ]),
+      ),
+      e.Addr(bobAddress),
     ],
   });
 
@@ -360,7 +327,7 @@ test("Change platform fee wallet", async () => {
       e.kvs.Mapper("end_date").Value(e.U64(END_DATE)),
       e.kvs.Mapper("refund_enabled").Value(e.Bool(Boolean(REFUND_ENABLED))),
       e.kvs.Mapper("refund_deadline").Value(e.U64(END_DATE)),
-      e.kvs.Mapper("platform_fee_wallet").Value(e.Addr(alice)),
+      e.kvs.Mapper("platform_fee_wallet").Value(e.Addr(bobAddress)),
       e.kvs.Mapper("group_fee_wallet").Value(e.Addr(deployer)),
       e.kvs
         .Mapper("payment_currencies")
@@ -32,17 +32,16 @@ import {
   REFUND_NOT_ENABLED,
   LOW_SOFT_CAP,
   TIMESTAMP_AFTER,
-  PAYMENT_NETWORK_ID,
-  DEPOSIT_ID,
 } from "./helpers.ts";
 
-import { bobAddress, SIGNATURE_BOB_REFUND } from "./signatures/bob.ts";
+import { bobAddress } from "./signatures/bob.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_AFTER,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  signOwnerCall,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
 import {
@@ -92,6 +91,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNATURE DEPLOYER
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U64(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -129,26 +129,11 @@ test("Refund with wrong signature", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -181,6 +166,7 @@ test("Refund with wrong signature", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -189,7 +175,7 @@ test("Refund with wrong signature", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -213,7 +199,7 @@ test("Refund with wrong signature", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -238,26 +224,11 @@ test("Refund by non owner", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -291,6 +262,8 @@ test("Refund by non owner", async () => {
       decimals,
     );
 
+    const depositId = `ID${i}`;
+
     const {
       address,
       whitelistSignature,
@@ -299,7 +272,7 @@ test("Refund by non owner", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -323,7 +296,7 @@ test("Refund by non owner", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -341,7 +314,10 @@ test("Refund by non owner", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_BOB_REFUND)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "refund", [], bobAddress)),
+      ],
     })
     .assertFail({ code: 4, message: "Only owner can call this function" });
 });
@@ -353,26 +329,11 @@ test("Refund with too much delay", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -405,6 +366,7 @@ test("Refund with too much delay", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -413,7 +375,7 @@ test("Refund with too much delay", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -437,7 +399,7 @@ test("Refund with too much delay", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -454,7 +416,10 @@ test("Refund with too much delay", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_DEPLOYER)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "refund")),
+      ],
     })
     .assertFail({ code: 4, message: "Function call took too long" });
 });
@@ -466,26 +431,12 @@ test("Refund while refunds not enabled", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_NOT_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      refundEnabled: REFUND_NOT_ENABLED,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -519,6 +470,8 @@ test("Refund while refunds not enabled", async () => {
       decimals,
     );
 
+    const depositId = `ID${i}`;
+
     const {
       address,
       whitelistSignature,
@@ -527,7 +480,7 @@ test("Refund while refunds not enabled", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -551,7 +504,7 @@ test("Refund while refunds not enabled", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -564,7 +517,10 @@ test("Refund while refunds not enabled", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_DEPLOYER)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "refund")),
+      ],
     })
     .assertFail({ code: 4, message: "Refunds are not enabled" });
 });
@@ -576,26 +532,11 @@ test("Refund while refunds not open", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -628,6 +569,7 @@ test("Refund while refunds not open", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -636,7 +578,7 @@ test("Refund while refunds not open", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -660,7 +602,7 @@ test("Refund while refunds not open", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -673,7 +615,10 @@ test("Refund while refunds not open", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_DEPLOYER)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "refund")),
+      ],
     })
     .assertFail({ code: 4, message: "Refunds are not open" });
 });
@@ -685,26 +630,11 @@ test("Refund after soft cap exceeded", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -737,6 +667,7 @@ test("Refund after soft cap exceeded", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -745,7 +676,7 @@ test("Refund after soft cap exceeded", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -769,7 +700,7 @@ test("Refund after soft cap exceeded", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -786,7 +717,10 @@ test("Refund after soft cap exceeded", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+      funcArgs: [
+        e.U64(TIMESTAMP_AFTER),
+        e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+      ],
     })
     .assertFail({ code: 4, message: "Soft cap exceeded" });
 });
@@ -798,26 +732,11 @@ test("Refund with not enough gas", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -854,6 +773,8 @@ test("Refund with not enough gas", async () => {
       decimals,
     );
 
+    const depositId = `ID${i}`;
+
     const {
       address,
       whitelistSignature,
@@ -862,7 +783,7 @@ test("Refund with not enough gas", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -886,7 +807,7 @@ test("Refund with not enough gas", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -907,7 +828,10 @@ test("Refund with not enough gas", async () => {
       callee: raisePoolContract,
       gasLimit: 5_000_000,
       funcName: "refund",
-      funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+      funcArgs: [
+        e.U64(TIMESTAMP_AFTER),
+        e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+      ],
     })
     .assertFail({ code: 5, message: "not enough gas" });
 }, 20000);
@@ -919,26 +843,11 @@ test("Refund in 1 call, deploy with ambassador", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -974,6 +883,7 @@ test("Refund in 1 call, deploy with ambassador", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -982,7 +892,7 @@ test("Refund in 1 call, deploy with ambassador", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1006,7 +916,7 @@ test("Refund in 1 call, deploy with ambassador", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -1039,7 +949,10 @@ test("Refund in 1 call, deploy with ambassador", async () => {
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+    ],
   });
 
   expect(state.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -1094,26 +1007,11 @@ test("Refund in 2 calls, deploy with ambassador", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1149,6 +1047,7 @@ test("Refund in 2 calls, deploy with ambassador", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1157,7 +1056,7 @@ test("Refund in 2 calls, deploy with ambassador", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1181,7 +1080,7 @@ test("Refund in 2 calls, deploy with ambassador", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -1214,16 +1113,23 @@ test("Refund in 2 calls, deploy with ambassador", async () => {
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+    ],
   });
 
   expect(state.returnData[0]).toBe(Buffer.from("interrupted").toString("hex"));
 
+  // Signatures are single-use, sign the second batch for another timestamp.
   let state2 = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER - 1),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER - 1, "refund")),
+    ],
   });
 
   expect(state2.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -1278,26 +1184,11 @@ test("Refund in 1 call, deploy without ambassador", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1333,6 +1224,7 @@ test("Refund in 1 call, deploy without ambassador", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1341,7 +1233,7 @@ test("Refund in 1 call, deploy without ambassador", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(0, depositAmount);
+    } = generateDataAndSignature(0, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1365,7 +1257,7 @@ test("Refund in 1 call, deploy without ambassador", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
       ],
       esdts: [{ id: currency, amount: depositAmount }],
     });
@@ -1396,7 +1288,10 @@ test("Refund in 1 call, deploy without ambassador", async () => {
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+    ],
   });
 
   expect(state.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -1451,26 +1346,11 @@ test("Refund in 2 calls, deploy without ambassador", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1506,6 +1386,7 @@ test("Refund in 2 calls, deploy without ambassador", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1514,7 +1395,7 @@ test("Refund in 2 calls, deploy without ambassador", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(0, depositAmount);
+    } = generateDataAndSignature(0, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1538,7 +1419,7 @@ test("Refund in 2 calls, deploy without ambassador", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
       ],
       esdts: [{ id: currency, amount: depositAmount }],
     });
@@ -1569,16 +1450,23 @@ test("Refund in 2 calls, deploy without ambassador", async () => {
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "refund")),
+    ],
   });
 
   expect(state.returnData[0]).toBe(Buffer.from("interrupted").toString("hex"));
 
+  // Signatures are single-use, sign the second batch for another timestamp.
   let state2 = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 5_000_000_000,
     funcName: "refund",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER - 1),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER - 1, "refund")),
+    ],
   });
 
   expect(state2.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -1,6 +1,7 @@
 import { test, beforeEach, afterEach, expect } from "vitest";
 import { Kvs } from "xsuite/dist/data/kvs";
 import { BytesLike, e } from "xsuite";
+import { Address, AddressValue } from "@multiversx/sdk-core";
 
 import {
   assertAccount,
@@ -32,17 +33,16 @@ import {
   REFUND_NOT_ENABLED,
   LOW_SOFT_CAP,
   TIMESTAMP_AFTER,
-  PAYMENT_NETWORK_ID,
-  DEPOSIT_ID,
 } from "./helpers.ts";
 
-import { bobAddress, SIGNATURE_BOB_REFUND } from "./signatures/bob.ts";
+import { bobAddress } from "./signatures/bob.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_AFTER,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  signOwnerCall,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
 import {
@@ -92,6 +92,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNATURE DEPLOYER
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U64(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -129,26 +130,11 @@ test("Refund admin with wrong signature", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -181,6 +167,7 @@ test("Refund admin with wrong signature", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -189,7 +176,7 @@ test("Refund admin with wrong signature", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -213,7 +200,7 @@ test("Refund admin with wrong signature", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -238,26 +225,11 @@ test("Refund admin by non owner", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -290,6 +262,7 @@ test("Refund admin by non owner", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -298,7 +271,7 @@ test("Refund admin by non owner", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -322,7 +295,7 @@ test("Refund admin by non owner", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -340,7 +313,10 @@ test("Refund admin by non owner", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "adminRefund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_BOB_REFUND)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "adminRefund", [], bobAddress)),
+      ],
     })
     .assertFail({ code: 4, message: "Only owner can call this function" });
 });
@@ -352,26 +328,11 @@ test("Refund admin with too much delay", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -404,6 +365,7 @@ test("Refund admin with too much delay", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -412,7 +374,7 @@ test("Refund admin with too much delay", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -436,7 +398,7 @@ test("Refund admin with too much delay", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -453,7 +415,10 @@ test("Refund admin with too much delay", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "adminRefund",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_DEPLOYER)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "adminRefund")),
+      ],
     })
     .assertFail({ code: 4, message: "Function call took too long" });
 });
@@ -466,26 +431,11 @@ test("Refund last N deposits", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -543,6 +493,7 @@ test("Refund last N deposits", async () => {
   var ambassadors: Encodable[] = [];
   var ambassadorsRefferalFees: TripleBigIntArray[] = [];
   var refundAddresses: Encodable[] = [];
+  var refundAddressValues: AddressValue[] = [];
   var totalAmbassadorsAmount: bigint = BigInt(0);
 
   const currenciesArray = [CURRENCY1, CURRENCY2, CURRENCY3];
@@ -564,6 +515,7 @@ test("Refund last N deposits", async () => {
       decimals,
     );
     const ambassadorBool = getRandomInt(0, 1);
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -572,7 +524,12 @@ test("Refund last N deposits", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(ambassadorBool, depositAmount);
+    } = generateDataAndSignature(
+      ambassadorBool,
+      currency,
+      depositAmount,
+      depositId,
+    );
 
     genericWallet = await world.createWallet({
       address: address,
@@ -597,7 +554,7 @@ test("Refund last N deposits", async () => {
           e.TopBuffer(depositSignature),
           e.U(platformFee),
           e.U(groupFee),
-          e.Str(DEPOSIT_ID),
+          e.Str(depositId),
           e.U(ambassadorFee),
           e.Addr(ambassadorAddress),
         ],
@@ -613,7 +570,7 @@ test("Refund last N deposits", async () => {
           e.TopBuffer(depositSignature),
           e.U(platformFee),
           e.U(groupFee),
-          e.Str(DEPOSIT_ID),
+          e.Str(depositId),
         ],
         esdts: [{ id: currency, amount: depositAmount }],
       });
@@ -700,6 +657,7 @@ test("Refund last N deposits", async () => {
       }
     } else {
       refundAddresses.push(e.Addr(address));
+      refundAddressValues.push(new AddressValue(new Address(address)));
     }
   }
 
@@ -730,7 +688,9 @@ test("Refund last N deposits", async () => {
     funcName: "adminRefund",
     funcArgs: [
       e.U64(TIMESTAMP),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
+      e.TopBuffer(
+        signOwnerCall(TIMESTAMP, "adminRefund", refundAddressValues),
+      ),
       ...refundAddresses,
     ],
   });
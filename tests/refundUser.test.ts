@@ -47,7 +47,8 @@ import {
   AMBASSADOR_FEE1,
   AMBASSADOR_FEE2,
   DEPOSIT_ID,
-  PAYMENT_NETWORK_ID,
+  DEPOSIT_ID2,
+  DEPOSIT_ID3,
 } from "./helpers.ts";
 
 import {
@@ -68,9 +69,10 @@ import {
 
 import {
   deployerAddress,
-  SIGNATURE_AFTER,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  signUserRefund,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
 } from "./signatures/deployer.ts";
 
 import {
@@ -121,6 +123,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNATURE DEPLOYER
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U64(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -158,26 +161,12 @@ test("Refund user while refund not enabled", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_DISABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      refundEnabled: REFUND_DISABLED,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -210,6 +199,7 @@ test("Refund user while refund not enabled", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -218,7 +208,7 @@ test("Refund user while refund not enabled", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -242,7 +232,7 @@ test("Refund user while refund not enabled", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -271,26 +261,11 @@ test("Refund user after refund deadline has passed", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -323,6 +298,7 @@ test("Refund user after refund deadline has passed", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -331,7 +307,7 @@ test("Refund user after refund deadline has passed", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -355,7 +331,7 @@ test("Refund user after refund deadline has passed", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -373,7 +349,7 @@ test("Refund user after refund deadline has passed", async () => {
         funcName: "userRefund",
         funcArgs: [
           e.U64(TIMESTAMP),
-          e.TopBuffer(depositSignature),
+          e.TopBuffer(signUserRefund(TIMESTAMP, address, currency)),
           e.Str(currency),
         ],
       })
@@ -388,26 +364,11 @@ test("Refund user with unregistered wallet", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -440,6 +401,7 @@ test("Refund user with unregistered wallet", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -448,7 +410,7 @@ test("Refund user with unregistered wallet", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -472,7 +434,7 @@ test("Refund user with unregistered wallet", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -508,26 +470,11 @@ test("Refund user with wrong signature", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(HIGH_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: HIGH_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -560,6 +507,7 @@ test("Refund user with wrong signature", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -568,7 +516,7 @@ test("Refund user with wrong signature", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -592,7 +540,7 @@ test("Refund user with wrong signature", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -619,26 +567,7 @@ test("Refund Bob with Currency1 after deposit Currency1, Currency2 with Bob, Cur
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -701,7 +630,7 @@ test("Refund Bob with Currency1 after deposit Currency1, Currency2 with Bob, Cur
       e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR2),
       e.U(PLATFORM_FEE2),
       e.U(GROUP_FEE2),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID2),
       e.U(AMBASSADOR_FEE2),
       e.Addr(deployer),
     ],
@@ -730,7 +659,7 @@ test("Refund Bob with Currency1 after deposit Currency1, Currency2 with Bob, Cur
       e.TopBuffer(SIGNATURE_CAROL_WITHOUT_AMBASSADOR),
       e.U(PLATFORM_FEE3),
       e.U(GROUP_FEE3),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   });
@@ -892,26 +821,7 @@ test("Refund Bob with Currency2 after deposit Currency1, Currency2 with Bob, Cur
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -974,7 +884,7 @@ test("Refund Bob with Currency2 after deposit Currency1, Currency2 with Bob, Cur
       e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR2),
       e.U(PLATFORM_FEE2),
       e.U(GROUP_FEE2),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID2),
       e.U(AMBASSADOR_FEE2),
       e.Addr(deployer),
     ],
@@ -1003,7 +913,7 @@ test("Refund Bob with Currency2 after deposit Currency1, Currency2 with Bob, Cur
       e.TopBuffer(SIGNATURE_CAROL_WITHOUT_AMBASSADOR),
       e.U(PLATFORM_FEE3),
       e.U(GROUP_FEE3),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   });
@@ -1164,26 +1074,7 @@ test("Refund Carol with Currency3 after deposit Currency1, Currency2 with Bob, C
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1246,7 +1137,7 @@ test("Refund Carol with Currency3 after deposit Currency1, Currency2 with Bob, C
       e.TopBuffer(SIGNATURE_BOB_WITH_AMBASSADOR2),
       e.U(PLATFORM_FEE2),
       e.U(GROUP_FEE2),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID2),
       e.U(AMBASSADOR_FEE2),
       e.Addr(deployer),
     ],
@@ -1275,7 +1166,7 @@ test("Refund Carol with Currency3 after deposit Currency1, Currency2 with Bob, C
       e.TopBuffer(SIGNATURE_CAROL_WITHOUT_AMBASSADOR),
       e.U(PLATFORM_FEE3),
       e.U(GROUP_FEE3),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   });
@@ -1,5 +1,6 @@
 import { test, beforeEach, afterEach, expect } from "vitest";
-import { BytesLike, e, Encodable } from "xsuite";
+import { BytesLike, e } from "xsuite";
+import { BooleanValue } from "@multiversx/sdk-core";
 import { Kvs } from "xsuite/dist/data/kvs";
 
 import { assertAccount, LSWorld, LSWallet, LSContract } from "xsuite";
@@ -7,8 +8,6 @@ import { assertAccount, LSWorld, LSWallet, LSContract } from "xsuite";
 import {
   POOL_ID,
   TIMESTAMP,
-  SOFT_CAP,
-  HARD_CAP,
   CURRENCY1,
   DECIMALS1,
   CURRENCY2,
@@ -18,9 +17,6 @@ import {
   MIN_DEPOSIT,
   MAX_DEPOSIT,
   DEPOSIT_INCREMENTS,
-  START_DATE,
-  END_DATE,
-  REFUND_ENABLED,
   DEPOSIT_TIMESTAMP,
   TIMESTAMP_AFTER,
   TIMESTAMP_WITH_DELAY,
@@ -28,19 +24,22 @@ import {
   LOW_HARD_CAP,
   HIGH_HARD_CAP,
   HIGH_SOFT_CAP,
-  PAYMENT_NETWORK_ID,
-  DEPOSIT_ID,
   CURRENCY3_DEPOSIT_AMOUNT,
+  DEPOSIT_ID3,
 } from "./helpers.ts";
 
 import {
   deployerAddress,
-  SIGNATURE_AFTER,
-  SIGNATURE_DEPLOYER,
   SIGNATURE_DUMMY,
+  signOwnerCall,
+  POOL_CONFIG,
+  deployRaisePoolArgs,
+  ReleasePlanEntry,
+  signReleasePlan,
+  releasePlanArgs,
 } from "./signatures/deployer.ts";
 
-import { bobAddress, SIGNATURE_BOB_REFUND } from "./signatures/bob.ts";
+import { bobAddress } from "./signatures/bob.ts";
 
 import {
   carolAddress,
@@ -53,6 +52,7 @@ import {
   generateDataAndSignature,
   getRandomInt,
   getRandomDeposit,
+  generateAddress,
 } from "./generator.ts";
 
 let world: LSWorld;
@@ -100,6 +100,7 @@ beforeEach(async () => {
       e.Addr(deployer), // GROUP FEE WALLET
       e.Addr(deployer), // SIGNATURE DEPLOYER
       e.Addr(walletDababaseContract), // WALLET DATABASE CONTRACT
+      e.TopBuffer(new Uint8Array(32)), // CONFIG HASH
       e.Str("Dummy1"), // CURRENCY1
       e.U64(0), // DECIMALS1
       e.Str("Dummy2"), // CURRENCY2
@@ -137,26 +138,10 @@ test("Release with wrong signature", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -189,6 +174,7 @@ test("Release with wrong signature", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -197,7 +183,7 @@ test("Release with wrong signature", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -221,7 +207,7 @@ test("Release with wrong signature", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -246,26 +232,11 @@ test("Release by non owner", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -298,6 +269,7 @@ test("Release by non owner", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -306,7 +278,7 @@ test("Release by non owner", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -330,7 +302,7 @@ test("Release by non owner", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -348,7 +320,10 @@ test("Release by non owner", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "release",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_BOB_REFUND)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "release", [], bobAddress)),
+      ],
     })
     .assertFail({ code: 4, message: "Only owner can call this function" });
 });
@@ -360,26 +335,11 @@ test("Release with too much delay", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_HARD_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_HARD_CAP,
+      hardCap: HIGH_HARD_CAP,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -412,6 +372,7 @@ test("Release with too much delay", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -420,7 +381,7 @@ test("Release with too much delay", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -444,7 +405,7 @@ test("Release with too much delay", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -461,7 +422,10 @@ test("Release with too much delay", async () => {
       callee: raisePoolContract,
       gasLimit: 50_000_000,
       funcName: "release",
-      funcArgs: [e.U64(TIMESTAMP), e.TopBuffer(SIGNATURE_DEPLOYER)],
+      funcArgs: [
+        e.U64(TIMESTAMP),
+        e.TopBuffer(signOwnerCall(TIMESTAMP, "release")),
+      ],
     })
     .assertFail({ code: 4, message: "Function call took too long" });
 });
@@ -469,33 +433,22 @@ test("Release with too much delay", async () => {
 test("Enable pool after release", async () => {
   const numberOfDeposits = 1;
 
-  platformWallet = await world.createWallet();
-  groupWallet = await world.createWallet();
+  const platformWalletAddress = generateAddress();
+  const groupWalletAddress = generateAddress();
+  platformWallet = await world.createWallet({ address: platformWalletAddress });
+  groupWallet = await world.createWallet({ address: groupWalletAddress });
 
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(platformWallet),
-      e.Addr(groupWallet),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      platformFeeWallet: platformWalletAddress,
+      groupFeeWallet: groupWalletAddress,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -534,6 +487,7 @@ test("Enable pool after release", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -542,7 +496,7 @@ test("Enable pool after release", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     currenciesPlatformFees[currencyRand] += platformFee;
     currenciesGroupFees[currencyRand] += groupFee;
@@ -569,7 +523,7 @@ test("Enable pool after release", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -608,7 +562,10 @@ test("Enable pool after release", async () => {
     callee: raisePoolContract,
     gasLimit: 500_000_000,
     funcName: "release",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "release")),
+    ],
   });
 
   expect(result.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -648,7 +605,11 @@ test("Enable pool after release", async () => {
       funcArgs: [
         e.Bool(true),
         e.U64(TIMESTAMP_AFTER),
-        e.TopBuffer(SIGNATURE_AFTER),
+        e.TopBuffer(
+          signOwnerCall(TIMESTAMP_AFTER, "enableRaisePool", [
+            new BooleanValue(true),
+          ]),
+        ),
       ],
     })
     .assertFail({
@@ -660,33 +621,22 @@ test("Enable pool after release", async () => {
 test("Release in 1 call no overcommitment", async () => {
   const numberOfDeposits = 140;
 
-  platformWallet = await world.createWallet();
-  groupWallet = await world.createWallet();
+  const platformWalletAddress = generateAddress();
+  const groupWalletAddress = generateAddress();
+  platformWallet = await world.createWallet({ address: platformWalletAddress });
+  groupWallet = await world.createWallet({ address: groupWalletAddress });
 
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(platformWallet),
-      e.Addr(groupWallet),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      platformFeeWallet: platformWalletAddress,
+      groupFeeWallet: groupWalletAddress,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -733,6 +683,7 @@ test("Release in 1 call no overcommitment", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -741,7 +692,7 @@ test("Release in 1 call no overcommitment", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -772,7 +723,7 @@ test("Release in 1 call no overcommitment", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -845,7 +796,10 @@ test("Release in 1 call no overcommitment", async () => {
     callee: raisePoolContract,
     gasLimit: 500_000_000,
     funcName: "release",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "release")),
+    ],
   });
 
   expect(result.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -900,33 +854,22 @@ test("Release in 1 call no overcommitment", async () => {
 test("Release in 2 calls no overcommitment", async () => {
   const numberOfDeposits = 200;
 
-  platformWallet = await world.createWallet();
-  groupWallet = await world.createWallet();
+  const platformWalletAddress = generateAddress();
+  const groupWalletAddress = generateAddress();
+  platformWallet = await world.createWallet({ address: platformWalletAddress });
+  groupWallet = await world.createWallet({ address: groupWalletAddress });
 
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(platformWallet),
-      e.Addr(groupWallet),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      platformFeeWallet: platformWalletAddress,
+      groupFeeWallet: groupWalletAddress,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -973,6 +916,7 @@ test("Release in 2 calls no overcommitment", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -981,7 +925,7 @@ test("Release in 2 calls no overcommitment", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1012,7 +956,7 @@ test("Release in 2 calls no overcommitment", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -1085,16 +1029,23 @@ test("Release in 2 calls no overcommitment", async () => {
     callee: raisePoolContract,
     gasLimit: 500_000_000,
     funcName: "release",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "release")),
+    ],
   });
 
   expect(result.returnData[0]).toBe(Buffer.from("interrupted").toString("hex"));
 
+  // Signatures are single-use, sign the second batch for another timestamp.
   let result2 = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 500_000_000,
     funcName: "release",
-    funcArgs: [e.U64(TIMESTAMP_AFTER), e.TopBuffer(SIGNATURE_AFTER)],
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER - 1),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER - 1, "release")),
+    ],
   });
 
   expect(result2.returnData[0]).toBe(Buffer.from("completed").toString("hex"));
@@ -1150,33 +1101,22 @@ test("Release in 1 call with N overcommitment", async () => {
   const numberOfDeposits = 160;
   const refundLast = 40;
 
-  platformWallet = await world.createWallet();
-  groupWallet = await world.createWallet();
+  const platformWalletAddress = generateAddress();
+  const groupWalletAddress = generateAddress();
+  platformWallet = await world.createWallet({ address: platformWalletAddress });
+  groupWallet = await world.createWallet({ address: groupWalletAddress });
 
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(platformWallet),
-      e.Addr(groupWallet),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      platformFeeWallet: platformWalletAddress,
+      groupFeeWallet: groupWalletAddress,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1212,7 +1152,7 @@ test("Release in 1 call with N overcommitment", async () => {
   var ambassadorWallets: LSWallet[] = [];
   var currencies: string[] = [];
   var ambassadorFees: bigint[] = [];
-  var refundAddresses: Encodable[] = [];
+  var releasePlan: ReleasePlanEntry[] = [];
 
   for (let i = 0; i < numberOfDeposits; i++) {
     const currencyRand = getRandomInt(0, 2);
@@ -1224,6 +1164,7 @@ test("Release in 1 call with N overcommitment", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1232,7 +1173,7 @@ test("Release in 1 call with N overcommitment", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1263,7 +1204,7 @@ test("Release in 1 call with N overcommitment", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -1315,7 +1256,7 @@ test("Release in 1 call with N overcommitment", async () => {
         groupFeeAmount -
         ambassadorFeeAmount;
     } else {
-      refundAddresses.push(e.Addr(address));
+      releasePlan.push([address, currency, depositAmount]);
     }
 
     // console.log(
@@ -1334,14 +1275,25 @@ test("Release in 1 call with N overcommitment", async () => {
     timestamp: TIMESTAMP_AFTER,
   });
 
+  await deployer.callContract({
+    callee: raisePoolContract,
+    gasLimit: 500_000_000,
+    funcName: "submitReleasePlan",
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signReleasePlan(TIMESTAMP_AFTER, true, releasePlan)),
+      e.Bool(true),
+      ...releasePlanArgs(releasePlan),
+    ],
+  });
+
   let result = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 500_000_000,
     funcName: "release",
     funcArgs: [
       e.U64(TIMESTAMP_AFTER),
-      e.TopBuffer(SIGNATURE_AFTER),
-      ...refundAddresses,
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "release")),
     ],
   });
 
@@ -1393,33 +1345,22 @@ test("Release in 2 calls with N overcommitment", async () => {
   const numberOfDeposits = 250;
   const refundLast = 150;
 
-  platformWallet = await world.createWallet();
-  groupWallet = await world.createWallet();
+  const platformWalletAddress = generateAddress();
+  const groupWalletAddress = generateAddress();
+  platformWallet = await world.createWallet({ address: platformWalletAddress });
+  groupWallet = await world.createWallet({ address: groupWalletAddress });
 
   await deployer.callContract({
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(LOW_SOFT_CAP),
-      e.U64(HIGH_HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(platformWallet),
-      e.Addr(groupWallet),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs({
+      ...POOL_CONFIG,
+      softCap: LOW_SOFT_CAP,
+      hardCap: HIGH_HARD_CAP,
+      platformFeeWallet: platformWalletAddress,
+      groupFeeWallet: groupWalletAddress,
+    }),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1455,7 +1396,7 @@ test("Release in 2 calls with N overcommitment", async () => {
   var ambassadorWallets: LSWallet[] = [];
   var currencies: string[] = [];
   var ambassadorFees: bigint[] = [];
-  var refundAddresses: Encodable[] = [];
+  var releasePlan: ReleasePlanEntry[] = [];
 
   for (let i = 0; i < numberOfDeposits; i++) {
     const currencyRand = getRandomInt(0, 2);
@@ -1467,6 +1408,7 @@ test("Release in 2 calls with N overcommitment", async () => {
       DEPOSIT_INCREMENTS,
       decimals,
     );
+    const depositId = `ID${i}`;
     const {
       address,
       whitelistSignature,
@@ -1475,7 +1417,7 @@ test("Release in 2 calls with N overcommitment", async () => {
       groupFee,
       ambassadorFee,
       ambassadorAddress,
-    } = generateDataAndSignature(1, depositAmount);
+    } = generateDataAndSignature(1, currency, depositAmount, depositId);
 
     genericWallet = await world.createWallet({
       address: address,
@@ -1506,7 +1448,7 @@ test("Release in 2 calls with N overcommitment", async () => {
         e.TopBuffer(depositSignature),
         e.U(platformFee),
         e.U(groupFee),
-        e.Str(DEPOSIT_ID),
+        e.Str(depositId),
         e.U(ambassadorFee),
         e.Addr(ambassadorAddress),
       ],
@@ -1558,7 +1500,7 @@ test("Release in 2 calls with N overcommitment", async () => {
         groupFeeAmount -
         ambassadorFeeAmount;
     } else {
-      refundAddresses.push(e.Addr(address));
+      releasePlan.push([address, currency, depositAmount]);
     }
 
     /*
@@ -1579,27 +1521,38 @@ test("Release in 2 calls with N overcommitment", async () => {
     timestamp: TIMESTAMP_AFTER,
   });
 
+  await deployer.callContract({
+    callee: raisePoolContract,
+    gasLimit: 500_000_000,
+    funcName: "submitReleasePlan",
+    funcArgs: [
+      e.U64(TIMESTAMP_AFTER),
+      e.TopBuffer(signReleasePlan(TIMESTAMP_AFTER, true, releasePlan)),
+      e.Bool(true),
+      ...releasePlanArgs(releasePlan),
+    ],
+  });
+
   let result = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 50_000_000_000,
     funcName: "release",
     funcArgs: [
       e.U64(TIMESTAMP_AFTER),
-      e.TopBuffer(SIGNATURE_AFTER),
-      ...refundAddresses,
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER, "release")),
     ],
   });
 
   expect(result.returnData[0]).toBe(Buffer.from("interrupted").toString("hex"));
 
+  // Signatures are single-use, sign the second batch for another timestamp.
   let result2 = await deployer.callContract({
     callee: raisePoolContract,
     gasLimit: 50_000_000_000,
     funcName: "release",
     funcArgs: [
-      e.U64(TIMESTAMP_AFTER),
-      e.TopBuffer(SIGNATURE_AFTER),
-      ...refundAddresses,
+      e.U64(TIMESTAMP_AFTER - 1),
+      e.TopBuffer(signOwnerCall(TIMESTAMP_AFTER - 1, "release")),
     ],
   });
 
@@ -1652,26 +1605,7 @@ test("Release with zero platform fee", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1713,7 +1647,7 @@ test("Release with zero platform fee", async () => {
       e.TopBuffer(SIGNATURE_CAROL_NO_FEES),
       e.U(0),
       e.U(0),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   }).assertFail({
@@ -1727,26 +1661,7 @@ test("Release with zero group fee", async () => {
     callee: factoryContract,
     gasLimit: 50_000_000,
     funcName: "deployRaisePool",
-    funcArgs: [
-      e.Str(POOL_ID),
-      e.U64(SOFT_CAP),
-      e.U64(HARD_CAP),
-      e.U64(MIN_DEPOSIT),
-      e.U64(MAX_DEPOSIT),
-      e.U64(DEPOSIT_INCREMENTS),
-      e.U64(START_DATE),
-      e.U64(END_DATE),
-      e.U64(REFUND_ENABLED),
-      e.U64(END_DATE),
-      e.Addr(deployer),
-      e.Addr(deployer),
-      e.TopBuffer(SIGNATURE_DEPLOYER),
-      e.U64(TIMESTAMP),
-      e.Str(PAYMENT_NETWORK_ID),
-      e.Str(CURRENCY1),
-      e.Str(CURRENCY2),
-      e.Str(CURRENCY3),
-    ],
+    funcArgs: deployRaisePoolArgs(POOL_CONFIG),
   });
 
   const raisePoolAddressResult = await deployer.query({
@@ -1788,7 +1703,7 @@ test("Release with zero group fee", async () => {
       e.TopBuffer(SIGNATURE_CAROL_ZERO_GROUP_FEE),
       e.U(1),
       e.U(0),
-      e.Str(DEPOSIT_ID),
+      e.Str(DEPOSIT_ID3),
     ],
     esdts: [{ id: CURRENCY3, amount: CURRENCY3_DEPOSIT_AMOUNT }],
   }).assertFail({
@@ -5,7 +5,6 @@ import {
   BinaryCodec,
   U32Value,
   U64Value,
-  BigUIntValue,
   StringValue,
 } from "@multiversx/sdk-core";
 
@@ -23,10 +22,19 @@ import {
   AFTER_DEPOSIT_TIMESTAMP,
   CURRENCY1,
   CURRENCY2,
+  CURRENCY1_DEPOSIT_AMOUNT,
+  CURRENCY2_DEPOSIT_AMOUNT,
+  DEPOSIT_ID2,
   BATCH_ID,
 } from "../helpers";
 
-import { deployerAddress, privateKeyDeployer } from "./deployer";
+import {
+  deployerAddress,
+  privateKeyDeployer,
+  signDeposit,
+  signUserRefund,
+  DepositSignatureData,
+} from "./deployer";
 
 const codec = new BinaryCodec();
 
@@ -38,46 +46,30 @@ export const bobAddress = privateKeyBob
   .generatePublicKey()
   .toAddress()
   .pubkey();
-const DATA_BOB = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-  codec.encodeNested(new BigUIntValue(Number(PLATFORM_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(GROUP_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(AMBASSADOR_FEE1))),
-  deployerAddress,
-]);
-export const SIGNATURE_BOB_WITH_AMBASSADOR = privateKeyDeployer.sign(DATA_BOB);
-
-const DATA_BOB2 = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-  codec.encodeNested(new BigUIntValue(Number(PLATFORM_FEE2))),
-  codec.encodeNested(new BigUIntValue(Number(GROUP_FEE2))),
-  codec.encodeNested(new BigUIntValue(Number(AMBASSADOR_FEE2))),
-  deployerAddress,
-]);
-export const SIGNATURE_BOB_WITH_AMBASSADOR2 =
-  privateKeyDeployer.sign(DATA_BOB2);
+export const BOB_DEPOSIT: DepositSignatureData = {
+  caller: bobAddress,
+  platformFee: PLATFORM_FEE1,
+  groupFee: GROUP_FEE1,
+  token: CURRENCY1,
+  amount: CURRENCY1_DEPOSIT_AMOUNT,
+  ambassadors: [[AMBASSADOR_FEE1, deployerAddress]],
+};
+export const SIGNATURE_BOB_WITH_AMBASSADOR = signDeposit(BOB_DEPOSIT);
 
-const DATA_BOB_AFTER = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP_AFTER)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-  codec.encodeNested(new BigUIntValue(Number(PLATFORM_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(GROUP_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(AMBASSADOR_FEE1))),
-  deployerAddress,
-]);
-export const SIGNATURE_BOB_AFTER = privateKeyDeployer.sign(DATA_BOB_AFTER);
+export const SIGNATURE_BOB_WITH_AMBASSADOR2 = signDeposit({
+  caller: bobAddress,
+  platformFee: PLATFORM_FEE2,
+  groupFee: GROUP_FEE2,
+  token: CURRENCY2,
+  amount: CURRENCY2_DEPOSIT_AMOUNT,
+  depositId: DEPOSIT_ID2,
+  ambassadors: [[AMBASSADOR_FEE2, deployerAddress]],
+});
 
-const DATA_BOB_REFUND = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-]);
-export const SIGNATURE_BOB_REFUND = privateKeyDeployer.sign(DATA_BOB_REFUND);
+export const SIGNATURE_BOB_AFTER = signDeposit({
+  ...BOB_DEPOSIT,
+  timestamp: TIMESTAMP_AFTER,
+});
 
 const DATA_WALLET = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
@@ -85,37 +77,21 @@ const DATA_WALLET = Buffer.concat([
 ]);
 export const SIGNATURE_BOB_WALLET = privateKeyDeployer.sign(DATA_WALLET);
 
-const DATA_BOB_AFTER_DEPOSIT = Buffer.concat([
-  codec.encodeNested(new U64Value(AFTER_DEPOSIT_TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-  codec.encodeNested(new BigUIntValue(Number(PLATFORM_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(GROUP_FEE1))),
-  codec.encodeNested(new BigUIntValue(Number(AMBASSADOR_FEE1))),
-  deployerAddress,
-]);
-export const SIGNATURE_BOB_AFTER_DEPOSIT = privateKeyDeployer.sign(
-  DATA_BOB_AFTER_DEPOSIT,
-);
+export const SIGNATURE_BOB_AFTER_DEPOSIT = signDeposit({
+  ...BOB_DEPOSIT,
+  timestamp: AFTER_DEPOSIT_TIMESTAMP,
+});
 
-const DATA_BOB_USER_REFUND_CURRENCY1 = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+export const SIGNATURE_DATA_BOB_USER_REFUND_CURRENCY1 = signUserRefund(
+  TIMESTAMP,
   bobAddress,
-  codec.encodeNested(StringValue.fromUTF8(CURRENCY1)),
-]);
-export const SIGNATURE_DATA_BOB_USER_REFUND_CURRENCY1 = privateKeyDeployer.sign(
-  DATA_BOB_USER_REFUND_CURRENCY1,
+  CURRENCY1,
 );
 
-const DATA_BOB_USER_REFUND_CURRENCY2 = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+export const SIGNATURE_DATA_BOB_USER_REFUND_CURRENCY2 = signUserRefund(
+  TIMESTAMP,
   bobAddress,
-  codec.encodeNested(StringValue.fromUTF8(CURRENCY2)),
-]);
-export const SIGNATURE_DATA_BOB_USER_REFUND_CURRENCY2 = privateKeyDeployer.sign(
-  DATA_BOB_USER_REFUND_CURRENCY2,
+  CURRENCY2,
 );
 
 const DATA_BOB_DISTRUBUTE = Buffer.concat([
@@ -127,12 +103,10 @@ const DATA_BOB_DISTRUBUTE = Buffer.concat([
 export const SIGNATURE_DATA_BOB_DISTRUBUTE =
   privateKeyDeployer.sign(DATA_BOB_DISTRUBUTE);
 
-const DATA_BOB_CUSTOM = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  bobAddress,
-  codec.encodeNested(new BigUIntValue(Number(19480))),
-  codec.encodeNested(new BigUIntValue(Number(876600))),
-]);
-export const SIGNATURE_DATA_BOB_CUSTOM =
-  privateKeyDeployer.sign(DATA_BOB_CUSTOM);
+export const SIGNATURE_DATA_BOB_CUSTOM = signDeposit({
+  caller: bobAddress,
+  platformFee: 19480,
+  groupFee: 876600,
+  token: CURRENCY1,
+  amount: 4870000,
+});
@@ -1,22 +1,23 @@
 import fs from "fs";
 import path from "path";
 import { UserSecretKey } from "@multiversx/sdk-wallet/out";
-import {
-  BinaryCodec,
-  U64Value,
-  BigUIntValue,
-  StringValue,
-} from "@multiversx/sdk-core";
+import { BinaryCodec, U64Value } from "@multiversx/sdk-core";
 
 import {
   TIMESTAMP,
-  POOL_ID,
   PLATFORM_FEE3,
   GROUP_FEE3,
   CURRENCY3,
+  CURRENCY3_DEPOSIT_AMOUNT,
+  DEPOSIT_ID3,
 } from "../helpers";
 
-import { deployerAddress, privateKeyDeployer } from "./deployer";
+import {
+  privateKeyDeployer,
+  signDeposit,
+  signUserRefund,
+  DepositSignatureData,
+} from "./deployer";
 
 const codec = new BinaryCodec();
 
@@ -28,16 +29,15 @@ export const carolAddress = privateKeyCarol
   .generatePublicKey()
   .toAddress()
   .pubkey();
-const DATA_CAROL_WITHOUT_AMBASSADOR = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  carolAddress,
-  codec.encodeNested(new BigUIntValue(PLATFORM_FEE3)),
-  codec.encodeNested(new BigUIntValue(GROUP_FEE3)),
-]);
-export const SIGNATURE_CAROL_WITHOUT_AMBASSADOR = privateKeyDeployer.sign(
-  DATA_CAROL_WITHOUT_AMBASSADOR,
-);
+export const CAROL_DEPOSIT: DepositSignatureData = {
+  caller: carolAddress,
+  platformFee: PLATFORM_FEE3,
+  groupFee: GROUP_FEE3,
+  token: CURRENCY3,
+  amount: CURRENCY3_DEPOSIT_AMOUNT,
+  depositId: DEPOSIT_ID3,
+};
+export const SIGNATURE_CAROL_WITHOUT_AMBASSADOR = signDeposit(CAROL_DEPOSIT);
 
 const DATA_WALLET = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
@@ -45,33 +45,20 @@ const DATA_WALLET = Buffer.concat([
 ]);
 export const SIGNATURE_CAROL_WALLET = privateKeyDeployer.sign(DATA_WALLET);
 
-const DATA_CAROL_USER_REFUND_CURRENCY3 = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  carolAddress,
-  codec.encodeNested(StringValue.fromUTF8(CURRENCY3)),
-]);
-export const SIGNATURE_DATA_CAROL_USER_REFUND_CURRENCY3 =
-  privateKeyDeployer.sign(DATA_CAROL_USER_REFUND_CURRENCY3);
-
-const DATA_CAROL_NO_FEES = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+export const SIGNATURE_DATA_CAROL_USER_REFUND_CURRENCY3 = signUserRefund(
+  TIMESTAMP,
   carolAddress,
-  codec.encodeNested(new BigUIntValue(0)),
-  codec.encodeNested(new BigUIntValue(0)),
-]);
-export const SIGNATURE_CAROL_NO_FEES = privateKeyDeployer.sign(
-  DATA_CAROL_NO_FEES,
+  CURRENCY3,
 );
 
-const DATA_CAROL_ZERO_GROUP_FEE = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  carolAddress,
-  codec.encodeNested(new BigUIntValue(1)),
-  codec.encodeNested(new BigUIntValue(0)),
-]);
-export const SIGNATURE_CAROL_ZERO_GROUP_FEE = privateKeyDeployer.sign(
-  DATA_CAROL_ZERO_GROUP_FEE,
-);
+export const SIGNATURE_CAROL_NO_FEES = signDeposit({
+  ...CAROL_DEPOSIT,
+  platformFee: 0,
+  groupFee: 0,
+});
+
+export const SIGNATURE_CAROL_ZERO_GROUP_FEE = signDeposit({
+  ...CAROL_DEPOSIT,
+  platformFee: 1,
+  groupFee: 0,
+});
@@ -5,15 +5,35 @@ import {
   BinaryCodec,
   U32Value,
   U64Value,
+  BigUIntValue,
+  BooleanValue,
   StringValue,
+  TypedValue,
+  OptionValue,
+  Address,
+  AddressValue,
+  TokenIdentifierValue,
 } from "@multiversx/sdk-core";
+import { keccak_256 } from "@noble/hashes/sha3";
+import { e, Encodable } from "xsuite";
 
 import {
   TIMESTAMP,
   POOL_ID,
-  TIMESTAMP_BEFORE,
-  TIMESTAMP_AFTER,
   BATCH_ID,
+  SOFT_CAP,
+  HARD_CAP,
+  MIN_DEPOSIT,
+  MAX_DEPOSIT,
+  DEPOSIT_INCREMENTS,
+  START_DATE,
+  END_DATE,
+  REFUND_ENABLED,
+  PAYMENT_NETWORK_ID,
+  CURRENCY1,
+  CURRENCY2,
+  CURRENCY3,
+  DEPOSIT_ID,
 } from "../helpers";
 
 const codec = new BinaryCodec();
@@ -26,31 +46,10 @@ export const deployerAddress = privateKeyDeployer
   .generatePublicKey()
   .toAddress()
   .pubkey();
-const DATA_DEPLOYER = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  deployerAddress,
-]);
-export const SIGNATURE_DEPLOYER = privateKeyDeployer.sign(DATA_DEPLOYER);
-
 export const SIGNATURE_DUMMY = privateKeyDeployer.sign(
   Buffer.from("SOME DUMMY DATA"),
 );
 
-const DATA_BEFORE = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP_BEFORE)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  deployerAddress,
-]);
-export const SIGNATURE_BEFORE = privateKeyDeployer.sign(DATA_BEFORE);
-
-const DATA_AFTER = Buffer.concat([
-  codec.encodeNested(new U64Value(TIMESTAMP_AFTER)),
-  codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
-  deployerAddress,
-]);
-export const SIGNATURE_AFTER = privateKeyDeployer.sign(DATA_AFTER);
-
 const DATA_DEPLOYER_DISTRUBUTE = Buffer.concat([
   codec.encodeNested(new U64Value(TIMESTAMP)),
   codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
@@ -60,3 +59,210 @@ const DATA_DEPLOYER_DISTRUBUTE = Buffer.concat([
 export const SIGNATURE_DATA_DEPLOYER_DISTRUBUTE = privateKeyDeployer.sign(
   DATA_DEPLOYER_DISTRUBUTE,
 );
+
+export type PoolConfig = {
+  poolId: string;
+  softCap: bigint | number;
+  hardCap: bigint | number;
+  minDeposit: bigint | number;
+  maxDeposit: bigint | number;
+  depositIncrements: bigint | number;
+  startDate: number;
+  endDate: number;
+  refundEnabled: number;
+  refundDeadline: number;
+  platformFeeWallet: Uint8Array;
+  groupFeeWallet: Uint8Array;
+  paymentNetworkId: string;
+  currencies: string[];
+};
+
+export const POOL_CONFIG: PoolConfig = {
+  poolId: POOL_ID,
+  softCap: SOFT_CAP,
+  hardCap: HARD_CAP,
+  minDeposit: MIN_DEPOSIT,
+  maxDeposit: MAX_DEPOSIT,
+  depositIncrements: DEPOSIT_INCREMENTS,
+  startDate: START_DATE,
+  endDate: END_DATE,
+  refundEnabled: REFUND_ENABLED,
+  refundDeadline: END_DATE,
+  platformFeeWallet: deployerAddress,
+  groupFeeWallet: deployerAddress,
+  paymentNetworkId: PAYMENT_NETWORK_ID,
+  currencies: [CURRENCY1, CURRENCY2, CURRENCY3],
+};
+
+// keccak256 of every deployRaisePool argument, nested encoded in declaration order and
+// with the currencies prefixed by their count.
+export function poolConfigHash(config: PoolConfig): Buffer {
+  const data = Buffer.concat([
+    codec.encodeNested(StringValue.fromUTF8(config.poolId)),
+    codec.encodeNested(new BigUIntValue(config.softCap)),
+    codec.encodeNested(new BigUIntValue(config.hardCap)),
+    codec.encodeNested(new BigUIntValue(config.minDeposit)),
+    codec.encodeNested(new BigUIntValue(config.maxDeposit)),
+    codec.encodeNested(new BigUIntValue(config.depositIncrements)),
+    codec.encodeNested(new U64Value(config.startDate)),
+    codec.encodeNested(new U64Value(config.endDate)),
+    codec.encodeNested(new BooleanValue(Boolean(config.refundEnabled))),
+    codec.encodeNested(new U64Value(config.refundDeadline)),
+    Buffer.from(config.platformFeeWallet),
+    Buffer.from(config.groupFeeWallet),
+    codec.encodeNested(StringValue.fromUTF8(config.paymentNetworkId)),
+    codec.encodeNested(new U32Value(config.currencies.length)),
+    ...config.currencies.map((currency) =>
+      codec.encodeNested(StringValue.fromUTF8(currency)),
+    ),
+  ]);
+  return Buffer.from(keccak_256(data));
+}
+
+export function signDeployRaisePool(
+  timestamp: number,
+  config: PoolConfig,
+  caller: Buffer = deployerAddress,
+): Buffer {
+  const data = Buffer.concat([
+    codec.encodeNested(new U64Value(timestamp)),
+    codec.encodeNested(StringValue.fromUTF8(config.poolId)),
+    caller,
+    poolConfigHash(config),
+  ]);
+  return privateKeyDeployer.sign(data);
+}
+
+export function deployRaisePoolArgs(
+  config: PoolConfig,
+  timestamp: number = TIMESTAMP,
+  signature: Buffer = signDeployRaisePool(timestamp, config),
+): Encodable[] {
+  return [
+    e.Str(config.poolId),
+    e.U(config.softCap),
+    e.U(config.hardCap),
+    e.U(config.minDeposit),
+    e.U(config.maxDeposit),
+    e.U(config.depositIncrements),
+    e.U64(config.startDate),
+    e.U64(config.endDate),
+    e.Bool(Boolean(config.refundEnabled)),
+    e.U64(config.refundDeadline),
+    e.Addr(config.platformFeeWallet),
+    e.Addr(config.groupFeeWallet),
+    e.TopBuffer(signature),
+    e.U64(timestamp),
+    e.Str(config.paymentNetworkId),
+    ...config.currencies.map((currency) => e.Str(currency)),
+  ];
+}
+
+// Owner and user calls sign the endpoint name followed by the nested encoded arguments,
+// so a signature is bound to one call and can only be used once.
+export function signOwnerCall(
+  timestamp: number,
+  endpoint: string,
+  args: TypedValue[] = [],
+  caller: Buffer = deployerAddress,
+): Buffer {
+  const data = Buffer.concat([
+    codec.encodeNested(new U64Value(timestamp)),
+    codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+    caller,
+    codec.encodeNested(StringValue.fromUTF8(endpoint)),
+    ...args.map((arg) => codec.encodeNested(arg)),
+  ]);
+  return privateKeyDeployer.sign(data);
+}
+
+// Overcommitted address, token and amount to send back before the release, an amount
+// covering the whole deposit refunds it in full.
+export type ReleasePlanEntry = [Buffer, string, bigint];
+
+export function signReleasePlan(
+  timestamp: number,
+  isFinal: boolean,
+  entries: ReleasePlanEntry[],
+): Buffer {
+  return signOwnerCall(timestamp, "submitReleasePlan", [
+    new BooleanValue(isFinal),
+    ...entries.flatMap(([address, token, amount]) => [
+      new AddressValue(new Address(address)),
+      new TokenIdentifierValue(token),
+      new BigUIntValue(amount),
+    ]),
+  ]);
+}
+
+export function releasePlanArgs(entries: ReleasePlanEntry[]): Encodable[] {
+  return entries.flatMap(([address, token, amount]) => [
+    e.Addr(address),
+    e.Str(token),
+    e.U(amount),
+  ]);
+}
+
+export function signUserRefund(
+  timestamp: number,
+  caller: Buffer,
+  token: string,
+): Buffer {
+  return signOwnerCall(
+    timestamp,
+    "userRefund",
+    [StringValue.fromUTF8(token)],
+    caller,
+  );
+}
+
+export type AmbassadorFee = [bigint | number, Uint8Array];
+
+export type DepositSignatureData = {
+  timestamp?: number;
+  caller: Buffer;
+  platformFee: bigint | number;
+  groupFee: bigint | number;
+  token: string;
+  amount: bigint | number;
+  depositId?: string;
+  maxAllocation?: bigint | number;
+  ambassadors?: AmbassadorFee[];
+};
+
+function depositData({
+  timestamp = TIMESTAMP,
+  caller,
+  platformFee,
+  groupFee,
+  token,
+  amount,
+  depositId = DEPOSIT_ID,
+  maxAllocation,
+  ambassadors = [],
+}: DepositSignatureData): Buffer {
+  return Buffer.concat([
+    codec.encodeNested(new U64Value(timestamp)),
+    codec.encodeNested(StringValue.fromUTF8(POOL_ID)),
+    caller,
+    codec.encodeNested(StringValue.fromUTF8("deposit")),
+    codec.encodeNested(new BigUIntValue(platformFee)),
+    codec.encodeNested(new BigUIntValue(groupFee)),
+    codec.encodeNested(StringValue.fromUTF8(token)),
+    codec.encodeNested(new BigUIntValue(amount)),
+    codec.encodeNested(StringValue.fromUTF8(depositId)),
+    codec.encodeNested(
+      maxAllocation === undefined
+        ? OptionValue.newMissing()
+        : OptionValue.newProvided(new BigUIntValue(maxAllocation)),
+    ),
+    ...ambassadors.flatMap(([fee, address]) => [
+      codec.encodeNested(new BigUIntValue(fee)),
+      Buffer.from(address),
+    ]),
+  ]);
+}
+
+export function signDeposit(data: DepositSignatureData): Buffer {
+  return privateKeyDeployer.sign(depositData(data));
+}